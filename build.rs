@@ -0,0 +1,41 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Bundles every `levels/*.txt` into the game, in play order, so adding
+/// a level is just dropping a file in the folder.
+fn main() {
+    let levels_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("levels");
+    println!("cargo:rerun-if-changed={}", levels_dir.display());
+
+    let mut files: Vec<String> = fs::read_dir(&levels_dir)
+        .expect("levels/ folder is missing")
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name.ends_with(".txt"))
+        .collect();
+
+    // level_2 before level_10: number in the name first, then the name
+    files.sort_by_key(|name| (level_number(name), name.clone()));
+    assert!(!files.is_empty(), "no level files in levels/");
+
+    let mut out = String::from("/// Level files bundled into the game, in play order.\n");
+    out.push_str("const LEVEL_FILES: &[(&str, &str)] = &[\n");
+    for name in &files {
+        let path = levels_dir.join(name);
+        println!("cargo:rerun-if-changed={}", path.display());
+        out.push_str(&format!("    ({name:?}, include_str!({:?})),\n", path.display().to_string()));
+    }
+    out.push_str("];\n");
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("levels.rs");
+    fs::write(dest, out).expect("couldn't write the level list");
+}
+
+fn level_number(name: &str) -> u32 {
+    name.chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .unwrap_or(u32::MAX)
+}
@@ -0,0 +1,190 @@
+use turbo::*;
+
+const CAUTION_FRAMES: u32 = 60 * 5;   // nothing suspicious this long → calm
+const ALARM_FRAMES: u32 = 60 * 8;     // nobody chasing this long → cooldown
+const COOLDOWN_FRAMES: u32 = 60 * 10; // …and this long again → calm
+const REINFORCE_EVERY: u32 = 60 * 6;  // frames between reinforcements
+const MAX_REINFORCEMENTS: u32 = 3;    // per level, however often the alarm goes up
+
+/// How on edge the whole level is.
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum AlarmLevel {
+    Calm,
+    Caution,  // someone's suspicious or searching
+    Alarm,    // Santa's been spotted, or something raised the alarm
+    Cooldown, // lost him – still jumpy
+}
+
+impl AlarmLevel {
+    pub fn label(self) -> &'static str {
+        match self {
+            AlarmLevel::Calm => "CALM",
+            AlarmLevel::Caution => "CAUTION",
+            AlarmLevel::Alarm => "ALARM",
+            AlarmLevel::Cooldown => "COOLDOWN",
+        }
+    }
+
+    pub fn color(self) -> u32 {
+        match self {
+            AlarmLevel::Calm => 0xffffffff,
+            AlarmLevel::Caution => 0xffdd55ff,
+            AlarmLevel::Alarm => 0xff4040ff,
+            AlarmLevel::Cooldown => 0xffa040ff,
+        }
+    }
+
+    /// Scales how far away snowmen pick Santa out
+    pub fn vision_factor(self) -> f32 {
+        match self {
+            AlarmLevel::Calm => 1.0,
+            AlarmLevel::Caution => 1.1,
+            AlarmLevel::Alarm => 1.3,
+            AlarmLevel::Cooldown => 1.15,
+        }
+    }
+
+    /// The music ducks under the tension (1.0 = full volume)
+    pub fn music_volume(self) -> f32 {
+        match self {
+            AlarmLevel::Calm => 1.0,
+            AlarmLevel::Caution => 0.75,
+            AlarmLevel::Alarm => 0.35,
+            AlarmLevel::Cooldown => 0.6,
+        }
+    }
+}
+
+/// The level's alarm: Calm → Caution → Alarm → Cooldown → Calm.
+/// Individual snowmen still chase on their own timers; this is what
+/// the level as a whole remembers.
+#[turbo::serialize]
+pub struct LevelAlarm {
+    pub level: AlarmLevel,
+    timer: u32, // frames left before it steps down
+    reinforce_timer: u32,
+    reinforcements: u32, // sent so far this level
+    pending_reinforcement: bool,
+    reported: AlarmLevel, // last level handed out by `take_change`
+}
+
+impl LevelAlarm {
+    pub fn new() -> Self {
+        Self {
+            level: AlarmLevel::Calm,
+            timer: 0,
+            reinforce_timer: 0,
+            reinforcements: 0,
+            pending_reinforcement: false,
+            reported: AlarmLevel::Calm,
+        }
+    }
+
+    /// Straight to Alarm (a camera, a tripwire, a fallen snowman…)
+    pub fn raise(&mut self) {
+        if self.level != AlarmLevel::Alarm {
+            self.level = AlarmLevel::Alarm;
+            self.reinforce_timer = REINFORCE_EVERY;
+        }
+        self.timer = ALARM_FRAMES;
+    }
+
+    /// `chasing`: some snowman is after Santa. `uneasy`: some snowman is
+    /// suspicious or searching. Counts down twice as fast while Santa hides.
+    pub fn update(&mut self, chasing: bool, uneasy: bool, hidden: bool) {
+        if chasing {
+            self.raise();
+        }
+
+        let decay = if hidden { 2 } else { 1 };
+
+        match self.level {
+            AlarmLevel::Calm => {
+                if uneasy {
+                    self.level = AlarmLevel::Caution;
+                    self.timer = CAUTION_FRAMES;
+                }
+            }
+            AlarmLevel::Caution => {
+                if uneasy {
+                    self.timer = CAUTION_FRAMES;
+                } else {
+                    self.timer = self.timer.saturating_sub(decay);
+                    if self.timer == 0 {
+                        self.level = AlarmLevel::Calm;
+                    }
+                }
+            }
+            AlarmLevel::Alarm => {
+                // 🚨 Call in help while it lasts
+                self.reinforce_timer = self.reinforce_timer.saturating_sub(1);
+                if self.reinforce_timer == 0 && self.reinforcements < MAX_REINFORCEMENTS {
+                    self.reinforcements += 1;
+                    self.reinforce_timer = REINFORCE_EVERY;
+                    self.pending_reinforcement = true;
+                }
+
+                if !chasing {
+                    self.timer = self.timer.saturating_sub(decay);
+                    if self.timer == 0 {
+                        self.level = AlarmLevel::Cooldown;
+                        self.timer = COOLDOWN_FRAMES;
+                    }
+                }
+            }
+            AlarmLevel::Cooldown => {
+                // Searches in progress hold it here
+                if !uneasy {
+                    self.timer = self.timer.saturating_sub(decay);
+                    if self.timer == 0 {
+                        self.level = AlarmLevel::Calm;
+                    }
+                }
+            }
+        }
+    }
+
+    /// The new level, once, on the frame after it changes.
+    pub fn take_change(&mut self) -> Option<AlarmLevel> {
+        if self.level == self.reported {
+            return None;
+        }
+        self.reported = self.level;
+        Some(self.level)
+    }
+
+    /// True once per reinforcement due.
+    pub fn take_reinforcement(&mut self) -> bool {
+        std::mem::take(&mut self.pending_reinforcement)
+    }
+
+    /// How much of the current stage is left (0.0–1.0), for the HUD.
+    fn remaining(&self) -> f32 {
+        let full = match self.level {
+            AlarmLevel::Calm => return 0.0,
+            AlarmLevel::Caution => CAUTION_FRAMES,
+            AlarmLevel::Alarm => ALARM_FRAMES,
+            AlarmLevel::Cooldown => COOLDOWN_FRAMES,
+        };
+        self.timer as f32 / full as f32
+    }
+
+    /// Badge under the left HUD, hidden while calm.
+    pub fn draw(&self, x: i32, y: i32) {
+        if self.level == AlarmLevel::Calm {
+            return;
+        }
+
+        let color = self.level.color();
+        let flash = self.level == AlarmLevel::Alarm && (time::tick() / 15).is_multiple_of(2);
+        let bg = if flash { 0x5a0d0dcc } else { 0x000000aa };
+
+        rect!(x = x, y = y, w = 90, h = 20, fixed = true, color = bg);
+        rect!(x = x, y = y, w = 3, h = 20, fixed = true, color = color);
+        text!(self.level.label(), x = x + 8, y = y + 3, fixed = true, scale = 1.2, color = color);
+
+        let fill = (82.0 * self.remaining()) as u32;
+        rect!(x = x + 8, y = y + 16, w = fill, h = 2, fixed = true, color = color);
+    }
+}
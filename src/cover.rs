@@ -0,0 +1,95 @@
+use turbo::*;
+use crate::{Grid, TileType, TILE_SIZE};
+
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum CoverKind {
+    Tree,
+    Presents,
+}
+
+/// A tile Santa can duck into. Snowmen can't see him there unless they
+/// are right next to him or already chasing.
+#[turbo::serialize]
+pub struct Cover {
+    pub tile: (usize, usize),
+    pub kind: CoverKind,
+}
+
+impl Cover {
+    pub fn new(tile: (usize, usize), kind: CoverKind) -> Self {
+        Self { tile, kind }
+    }
+
+    /// Whether the world point (x, y) is inside this cover's tile.
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= 0.0
+            && y >= 0.0
+            && (x as i32 / TILE_SIZE) as usize == self.tile.0
+            && (y as i32 / TILE_SIZE) as usize == self.tile.1
+    }
+
+    pub fn draw(&self) {
+        let px = (self.tile.0 as i32) * TILE_SIZE;
+        let py = (self.tile.1 as i32) * TILE_SIZE;
+
+        match self.kind {
+            CoverKind::Tree => {
+                sprite!(
+                    "decor/decor_tree_christmas",
+                    x = px - TILE_SIZE / 2,
+                    y = py - TILE_SIZE,
+                    w = (TILE_SIZE * 2) as u32,
+                    h = (TILE_SIZE * 2) as u32,
+                    cover = true
+                );
+            }
+
+            CoverKind::Presents => {
+                // 🎁 Three gifts piled up, back to front
+                for (ox, oy, size) in [(2, 0, 20), (12, 8, 22), (-2, 12, 20)] {
+                    sprite!(
+                        "decor/gift",
+                        x = px + ox,
+                        y = py + oy,
+                        w = size,
+                        h = size,
+                        cover = true
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Authored cover plus a tree in every dead-end (floor with walls on three sides).
+pub fn find_cover(grid: &Grid, authored: &[((usize, usize), CoverKind)]) -> Vec<Cover> {
+    let mut covers: Vec<Cover> = authored
+        .iter()
+        .map(|&(tile, kind)| Cover::new(tile, kind))
+        .collect();
+
+    let h = grid.len();
+    let w = grid[0].len();
+
+    for y in 1..h - 1 {
+        for x in 1..w - 1 {
+            if grid[y][x].tile_type != TileType::Floor {
+                continue;
+            }
+
+            let up    = grid[y - 1][x].tile_type == TileType::Wall;
+            let down  = grid[y + 1][x].tile_type == TileType::Wall;
+            let left  = grid[y][x - 1].tile_type == TileType::Wall;
+            let right = grid[y][x + 1].tile_type == TileType::Wall;
+
+            if (up as u8 + down as u8 + left as u8 + right as u8) == 3
+                && !covers.iter().any(|c| c.tile == (x, y))
+            {
+                covers.push(Cover::new((x, y), CoverKind::Tree));
+            }
+        }
+    }
+
+    covers
+}
@@ -0,0 +1,37 @@
+use turbo::*;
+
+const FOOTPRINT_LIFETIME: u32 = 60 * 12; // frames before the snow fills it in
+const MAX_ALPHA: f32 = 90.0;
+
+/// A print Santa left in the snow. Hounds follow the freshest ones.
+#[turbo::serialize]
+pub struct Footprint {
+    pub x: f32,
+    pub y: f32,
+    pub stamp: u32, // footstep number – bigger is fresher
+    age: u32,
+}
+
+impl Footprint {
+    pub fn new(x: f32, y: f32, stamp: u32) -> Self {
+        Self { x, y, stamp, age: 0 }
+    }
+
+    /// Returns false once it has filled in.
+    pub fn update(&mut self) -> bool {
+        self.age += 1;
+        self.age < FOOTPRINT_LIFETIME
+    }
+
+    pub fn draw(&self) {
+        let alpha = ((1.0 - self.age as f32 / FOOTPRINT_LIFETIME as f32) * MAX_ALPHA) as u32;
+        let side = if self.stamp.is_multiple_of(2) { -3 } else { 3 };
+
+        circ!(
+            x = self.x as i32 + side - 2,
+            y = self.y as i32 - 2,
+            d = 4,
+            color = 0x3a4a6000 | alpha
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use turbo::*;
 
+/// Rounds in a gun gift (the first one also hands over the gun)
+pub const GUN_GIFT_ROUNDS: u32 = 12;
+
 #[turbo::serialize]
 #[derive(Copy, PartialEq)]
 pub enum GiftType {
@@ -3,36 +3,45 @@ use turbo::camera;
 use crate::map::{
     draw_map,
     draw_background,
-    draw_corner_trees,
     draw_lamp_posts,
     draw_border_christmas_lights,
+    draw_exit,
 };
 
 
 mod map;
 mod player;
 mod model;
-mod bullet;
+mod projectile;
 mod snow;
 mod gift;
 
 
 
-use bullet::Bullet;
+use projectile::{KillAlert, Projectile, ProjectileKind};
 use model::*;
 use player::Player;
 
 
 mod start_screen;
 use start_screen::StartScreen;
-use snow::{spawn_snow, Snow};
-mod player_snowball;
-use player_snowball::Snowball;
 use model::Boss;
-use gift::{Gift, GiftType};
-mod player_bullet;
-use player_bullet::PlayerBullet;
-use player::WeaponMode;
+use gift::{Gift, GiftType, GUN_GIFT_ROUNDS};
+mod noise;
+mod cover;
+mod footprint;
+use footprint::Footprint;
+mod sentry;
+use sentry::{SecurityCamera, Trap};
+mod alarm;
+use alarm::{AlarmLevel, LevelAlarm};
+use cover::{Cover, find_cover};
+mod lighting;
+use lighting::LightMap;
+mod settings;
+use settings::Settings;
+use noise::{Noise, GIFT_PICKUP_NOISE};
+use player::{DamageSource, Gait, WeaponMode};
 
 
 
@@ -43,10 +52,12 @@ struct GameState {
     player: Player,
     map: Grid,
     enemies: Vec<Enemy>,
-    bullets: Vec<Bullet>,
+    projectiles: Vec<Projectile>, // ❄️🔫 everyone's shots
     level: u32, // 👈 ADD THIS
+    level_info: LevelHeader,
+    spawn: SpawnInfo,
     level_banner_timer: u32,
-    player_snowballs: Vec<Snowball>,
+    phase_banner_timer: u32, // 👹 boss phase name on screen
     lose_timer: u32,
     gifts: Vec<Gift>, // 🎁 NEW
     gift_timer: u32,        // ⏱ runtime counter
@@ -55,9 +66,18 @@ struct GameState {
     frame: u32,
     boss: Option<Boss>,
 
-    player_bullets: Vec<PlayerBullet>,
+    noises: Vec<Noise>, // 🔊 ripples still fading out
+    footprints: Vec<Footprint>, // 🐾 Santa's trail in the snow
+    footsteps: u32,             // prints made so far – newest has the biggest stamp
+    covers: Vec<Cover>, // 🌲 hiding spots
+    cameras: Vec<SecurityCamera>, // 📷 sweep for Santa, snowball to disable
+    traps: Vec<Trap>,             // 🪤 tripwires / pressure plates
+    alarm: LevelAlarm,            // 🚨 calm → caution → alarm → cooldown
+    light: LightMap,    // 💡 lamps + ambient darkness
+    settings: Settings, // ⚙️ O to open
+    camera_shake: f32, // px, decays each frame
     music_timer: u32,
-music_phase: u8, // 0 = snowmusic, 1 = backgroundmusic
+music_phase: u8, // 0 = level music, 1 = backgroundmusic
 
 
 
@@ -68,6 +88,7 @@ impl GameState {
 fn alert_all_snowmen(&mut self) {
     let px = self.player.x;
     let py = self.player.y;
+    self.alarm.raise();
 
     for enemy in self.enemies.iter_mut() {
         if enemy.alive {
@@ -80,6 +101,7 @@ fn alert_all_snowmen(&mut self) {
 
     fn alert_nearby_snowmen(&mut self, x: f32, y: f32) {
     const ALERT_RADIUS: f32 = 220.0;
+    self.alarm.raise();
 
     for enemy in self.enemies.iter_mut() {
         if !enemy.alive {
@@ -98,16 +120,83 @@ fn alert_all_snowmen(&mut self) {
     audio::play("alert");
 }
 
+    /// 📻 A chasing snowman tells everyone in range where Santa was.
+    /// Radio ignores walls, unlike noise.
+    fn radio_call(&mut self, (x, y): (f32, f32), last_seen: (f32, f32)) {
+        const RADIO_RANGE: f32 = 280.0;
+
+        for enemy in self.enemies.iter_mut() {
+            let dx = enemy.x + 16.0 - x;
+            let dy = enemy.y + 16.0 - y;
+
+            if (dx * dx + dy * dy).sqrt() <= RADIO_RANGE {
+                enemy.hear_call(last_seen);
+            }
+        }
+
+        // Visual only – a ripple, but nobody "hears" it as noise
+        self.noises.push(Noise::new(x, y, RADIO_RANGE));
+        audio::play("radio");
+    }
+
+    /// 🚨 A snowman turns up a good walk away from Santa, already hunting him.
+    fn call_reinforcement(&mut self) {
+        const MIN_STEPS: usize = 8; // out of sight, not on top of him
+        const MAX_STEPS: usize = 14;
+
+        let player_tile = self.player_tile();
+
+        let mut spots: Vec<(usize, usize)> = tiles_within_steps(&self.map, player_tile, MAX_STEPS)
+            .into_iter()
+            .filter(|&(_, steps)| steps >= MIN_STEPS)
+            .map(|(tile, _)| tile)
+            .collect();
+        random::shuffle(&mut spots);
+
+        for (tx, ty) in spots {
+            let (sx, sy) = tile_to_world(tx, ty);
+            if is_spawn_position_valid(&self.map, sx, sy, 32.0) {
+                let mut enemy = Enemy::new(EnemyKind::Snowman, sx, sy);
+                enemy.alert(self.player.x, self.player.y);
+                self.enemies.push(enemy);
+                return;
+            }
+        }
+    }
+
+    /// Level music and the background track share one volume.
+    fn set_music_volume(&self, volume: f32) {
+        audio::set_volume(&self.level_info.music, volume);
+        audio::set_volume("backgroundmusic", volume);
+    }
+
+    /// 🔊 Snowmen within earshot (walls muffle it) go to investigate.
+    fn make_noise(&mut self, x: f32, y: f32, radius: f32) {
+        let noise = Noise::new(x, y, radius);
+
+        for enemy in self.enemies.iter_mut() {
+            if enemy.alive && noise.heard_at(&self.map, enemy.x + 16.0, enemy.y + 16.0) {
+                enemy.hear(x, y);
+            }
+        }
+
+        self.noises.push(noise);
+    }
+
 
 fn new() -> Self {
 
     camera::reset();
 
     // Load level 1
-    let (map, spawn) = load_level_by_index(1);
+    let (level_info, map, spawn) = load_level_by_index(1);
 
     // Spawn enemies
-    let enemies = Self::spawn_enemies(&map, 1);
+    let enemies = Self::spawn_enemies(&map, &spawn, level_info.enemy_count);
+    let covers = find_cover(&map, &spawn.cover);
+    let cameras = spawn.cameras.iter().map(|c| SecurityCamera::new(c.tile, c.sweep)).collect();
+    let traps = spawn.traps.iter().map(|&(tile, kind)| Trap::new(tile, kind)).collect();
+    let light = LightMap::new(&map, level_info.darkness);
 
     // Create player ONCE
     let mut player = Player::new();
@@ -120,10 +209,12 @@ fn new() -> Self {
         player,                 // ✅ USE THE POSITIONED PLAYER
         map,
         enemies,
-        bullets: Vec::new(),
+        projectiles: Vec::new(),
         level: 1,
+        level_info,
+        spawn,
         level_banner_timer: 120,
-        player_snowballs: Vec::new(),
+        phase_banner_timer: 0,
         lose_timer: 0,
 
         // 🎁 Gifts
@@ -136,7 +227,16 @@ fn new() -> Self {
 
         // 👹 Boss (none at level 1)
         boss: None,
-        player_bullets: Vec::new(),
+        noises: Vec::new(),
+        footprints: Vec::new(),
+        footsteps: 0,
+        covers,
+        cameras,
+        traps,
+        alarm: LevelAlarm::new(),
+        light,
+        settings: Settings::new(),
+        camera_shake: 0.0,
         music_timer: 0,
 music_phase: 0,
 
@@ -154,38 +254,16 @@ music_phase: 0,
         (self.map.len() as i32 * TILE_SIZE) as f32
     }
 
-    fn spawn_boss(&self) -> Boss {
-        let mut candidates = Vec::new();
-
-        for (ty, row) in self.map.iter().enumerate() {
-            for (tx, tile) in row.iter().enumerate() {
-                if tile.tile_type == TileType::Floor {
-                    let x = tx as f32 * TILE_SIZE as f32;
-                    let y = ty as f32 * TILE_SIZE as f32;
-
-                    // IMPORTANT: size must match boss collision expectations
-                    if is_spawn_position_valid(&self.map, x, y, 64.0) {
-                        candidates.push((x, y));
-                    }
-                }
-            }
-        }
-
-        random::shuffle(&mut candidates);
-
-        let (x, y) = candidates
-            .first()
-            .expect("No valid boss spawn location");
-
-        Boss::new(*x, *y)
-    }
-
-
-    fn spawn_enemies(map: &Grid, level: u32) -> Vec<Enemy> {
+    fn spawn_enemies(map: &Grid, spawn_info: &SpawnInfo, count: usize) -> Vec<Enemy> {
         let mut enemies = Vec::new();
         let mut floor_tiles = Vec::new();
 
-        let (_map, spawn_info) = load_level_by_index(level);
+        // 📍 Authored snowmen first
+        for marker in spawn_info.enemies.iter() {
+            enemies.push(Enemy::from_marker(marker));
+        }
+
+        // 🎲 Fill the rest at random
         let reachable = compute_reachable(map, spawn_info.player_spawn);
 
         for (ty, row) in map.iter().enumerate() {
@@ -198,18 +276,16 @@ music_phase: 0,
 
         random::shuffle(&mut floor_tiles);
 
-        const ENEMY_COUNT: usize = 5;
-
         for (tx, ty) in floor_tiles.into_iter() {
+            if enemies.len() >= count {
+                break;
+            }
+
             let x = (tx as i32 * TILE_SIZE) as f32;
             let y = (ty as i32 * TILE_SIZE) as f32;
 
             if is_spawn_position_valid(map, x, y, 32.0) {
-                enemies.push(Enemy::new(x, y));
-            }
-
-            if enemies.len() == ENEMY_COUNT {
-                break;
+                enemies.push(Enemy::new(EnemyKind::Snowman, x, y));
             }
         }
 
@@ -235,6 +311,22 @@ music_phase: 0,
 
 
 fn spawn_gifts(&self) -> Vec<Gift> {
+    // 📍 Authored gift spots replace the random pair
+    if !self.spawn.gifts.is_empty() {
+        return self
+            .spawn
+            .gifts
+            .iter()
+            .map(|&((tx, ty), kind)| {
+                Gift::new(
+                    tx as f32 * TILE_SIZE as f32 + 8.0,
+                    ty as f32 * TILE_SIZE as f32 + 8.0,
+                    kind,
+                )
+            })
+            .collect();
+    }
+
     let mut tiles = Vec::new();
 
     for (ty, row) in self.map.iter().enumerate() {
@@ -273,18 +365,38 @@ fn spawn_gifts(&self) -> Vec<Gift> {
     fn advance_level(&mut self) {
         self.level += 1;
 
-        let (map, spawn) = load_level_by_index(self.level);
+        let (level_info, map, spawn) = load_level_by_index(self.level);
         self.map = map;
 
+        // 🎵 New track → restart the music cycle
+        if level_info.music != self.level_info.music {
+            audio::stop(&self.level_info.music);
+            audio::stop("backgroundmusic");
+            self.music_phase = 0;
+            self.music_timer = 0;
+        }
+        self.level_info = level_info;
+
         let (sx, sy) = tile_to_world(spawn.player_spawn.0, spawn.player_spawn.1);
         self.player = Player::new();
         self.player.x = sx;
         self.player.y = sy;
 
-        self.enemies = Self::spawn_enemies(&self.map, self.level);
-        self.bullets.clear();
+        self.enemies =
+            Self::spawn_enemies(&self.map, &spawn, self.level_info.enemy_count);
+        self.covers = find_cover(&self.map, &spawn.cover);
+        self.cameras = spawn.cameras.iter().map(|c| SecurityCamera::new(c.tile, c.sweep)).collect();
+        self.traps = spawn.traps.iter().map(|&(tile, kind)| Trap::new(tile, kind)).collect();
+        self.alarm = LevelAlarm::new();
+        self.set_music_volume(AlarmLevel::Calm.music_volume());
+        self.light = LightMap::new(&self.map, self.level_info.darkness);
+        self.spawn = spawn;
+        self.projectiles.clear();
+        self.noises.clear();
+        self.footprints.clear();
 
 self.boss = None;
+        self.phase_banner_timer = 0;
 
 
         // 🎬 EFFECTS
@@ -322,11 +434,11 @@ if self.flow == GameFlow::Playing {
 
 // Phase 0 → Snow music (1:18 = 78s = 4680 frames)
 if self.music_phase == 0 && self.music_timer == 1 {
-    audio::play("snowmusic");
+    audio::play(&self.level_info.music);
 }
 
 if self.music_phase == 0 && self.music_timer >= 4680 {
-    audio::stop("snowmusic");
+    audio::stop(&self.level_info.music);
     audio::play("backgroundmusic");
 
     self.music_phase = 1;
@@ -341,11 +453,8 @@ if self.music_phase == 1 && self.music_timer >= 9540 {
 }
 
 
-        let kb = keyboard::get();
-        
-        
         if self.flow == GameFlow::Lose {
-            audio::stop("snowmusic");
+            audio::stop(&self.level_info.music);
 audio::stop("backgroundmusic");
 
 
@@ -387,6 +496,13 @@ audio::stop("backgroundmusic");
             }
         }
 
+        // ⚙️ SETTINGS PANEL (pauses the game)
+        if self.settings.update() {
+            self.draw_game_world();
+            self.settings.draw();
+            return;
+        }
+
         if self.level_banner_timer > 0 {
             self.level_banner_timer -= 1;
         }
@@ -399,7 +515,7 @@ audio::stop("backgroundmusic");
 
         // 🎁 Spawn gifts ONCE at random time
         if !self.gifts_spawned && self.gift_timer >= self.gift_spawn_time {
-            self.gifts = self.spawn_gifts(); // exactly 2
+            self.gifts = self.spawn_gifts(); // 2 random, or the authored ones
             self.gifts_spawned = true;
         }
         // ⏳ Remove gifts after 10 seconds
@@ -411,9 +527,18 @@ audio::stop("backgroundmusic");
         }
 
         // Update player
-        self.player.update(&self.map);
+        if let Some(radius) = self.player.update(&self.map) {
+            self.make_noise(self.player.x + 16.0, self.player.y + 16.0, radius);
+
+            // 🐾 Every step leaves a print for the hounds
+            self.footsteps += 1;
+            self.footprints.push(Footprint::new(self.player.x + 16.0, self.player.y + 30.0, self.footsteps));
+        }
+        self.footprints.retain_mut(|print| print.update());
 
         // 🎁 Gift pickup logic
+        let mut pending_noises: Vec<(f32, f32, f32)> = Vec::new();
+
         for gift in self.gifts.iter_mut() {
             if !gift.alive {
                 continue;
@@ -421,6 +546,7 @@ audio::stop("backgroundmusic");
 
             if gift.hitbox().intersects(&self.player.hitbox) {
                 gift.alive = false;
+                pending_noises.push((gift.x + 12.0, gift.y + 12.0, GIFT_PICKUP_NOISE));
 
                 match gift.kind {
                     GiftType::Life => {
@@ -429,7 +555,10 @@ audio::stop("backgroundmusic");
                         audio::play("pickup");
                     }
                     GiftType::Bullet => {
-                        self.player.weapon = WeaponMode::Gun;
+                        // New gun → straight into Santa's hands; otherwise just ammo
+                        if self.player.inventory.add_ammo(WeaponMode::Gun, GUN_GIFT_ROUNDS) {
+                            self.player.inventory.select(WeaponMode::Gun);
+                        }
                         audio::play("powerup");
                     }
                 }
@@ -442,86 +571,221 @@ audio::stop("backgroundmusic");
         self.gifts.retain(|g| g.alive);
 
 
-        // ❄ Throw snowball
-        if keyboard::get().space().just_pressed() {
+        // 🤫 Silent takedown (no alert, no noise)
+        if keyboard::get().key_e().just_pressed()
+            && let Some(i) = self.takedown_target()
+        {
+            self.enemies[i].take_down(self.player.x);
+        }
+
+        // 🔁 Q cycles weapons, R reloads
+        if keyboard::get().key_q().just_pressed() {
+            self.player.inventory.cycle();
+        }
+        if keyboard::get().key_r().just_pressed() {
+            self.player.inventory.start_reload();
+        }
+
+        // ❄ Attack with whatever Santa has out (SPACE or click)
+        if keyboard::get().space().just_pressed() || pointer::screen().just_pressed() {
             let cx = self.player.x + 16.0;
             let cy = self.player.y + 16.0;
-            let dir = self.player.facing;
+            let dir = self.player.aim;
 
-            match self.player.weapon {
-                WeaponMode::Snowball => {
-                    self.player_snowballs.push(
-                        Snowball::new(cx, cy, dir)
-                    );
+            if self.player.inventory.weapon() == WeaponMode::CandyCane {
+                if self.player.start_swing() {
                     audio::play("throw");
                 }
-                WeaponMode::Gun => {
-                    self.player_bullets.push(
-                        PlayerBullet::new(cx, cy, dir)
-                    );
-                    audio::play("shoot");
-                }
+            } else if let Some(kind) = self.player.inventory.try_fire() {
+                self.fire(kind, cx, cy, dir, &mut pending_noises);
             }
         }
 
         let mut pending_alerts: Vec<(f32, f32)> = Vec::new();
 
-for ball in self.player_snowballs.iter_mut() {
-    // Snapshot enemy alive state BEFORE update
-    let before_alive: Vec<bool> =
-        self.enemies.iter().map(|e| e.alive).collect();
-
-    // Update snowball (may kill enemies)
-    ball.update(&self.map, &mut self.enemies);
+        // 🍬 Candy cane connects mid-swing
+        if self.player.swing_impact() {
+            for enemy in self.enemies.iter_mut() {
+                if enemy.alive && self.player.in_swing_arc(enemy.x + 16.0, enemy.y + 16.0) {
+                    audio::play("hit");
+                    if enemy.take_hit(self.player.x, self.player.y) {
+                        pending_alerts.push((enemy.x, enemy.y));
+                    }
+                }
+            }
 
-    // Detect newly killed snowmen
-    for (i, was_alive) in before_alive.iter().enumerate() {
-        if *was_alive && !self.enemies[i].alive {
-            pending_alerts.push((
-                self.enemies[i].x,
-                self.enemies[i].y,
-            ));
+            if let Some(boss) = &mut self.boss {
+                let (bx, by) = boss.center();
+
+                if self.player.in_swing_arc(bx, by) {
+                    match boss.take_damage(3) { // between SNOWBALL and GUN
+                        BossHit::Landed => {
+                            boss.knock_back(bx - (self.player.x + 16.0), by - (self.player.y + 16.0), 6.0);
+                            audio::play("hit");
+                        }
+                        BossHit::Blocked => audio::play("block"),
+                        BossHit::Ignored => {}
+                    }
+                }
+            }
         }
-    }
 
-    // 👹 Boss hit check (UNCHANGED)
-    if let Some(boss) = &mut self.boss {
-        if ball.alive {
-            let ball_box = Bounds::new(
-                ball.x - 3.0,
-                ball.y - 3.0,
-                6,
-                6,
-            );
+        // ❄️🔫 Projectiles (swept hits, damage from the weapon definition)
+        let mut alert_everyone = false;
+
+        for shot in self.projectiles.iter_mut() {
+            let def = shot.def();
+
+            if shot.update(&self.map) && def.impact_noise > 0.0 {
+                pending_noises.push((shot.x, shot.y, def.impact_noise));
+            }
+
+            // 🎯 Snowmen's and the boss's shots only look for Santa
+            if let Some(source) = def.hostile {
+                if shot.alive && shot.hits(&self.player.hitbox) {
+                    // Spent either way – i-frames just stop it hurting
+                    if self.player.take_damage(def.damage, source, shot.prev_xy()) {
+                        audio::play(def.hit_sound);
+                    }
+                    shot.register_hit();
+                }
+                continue;
+            }
+
+            for enemy in self.enemies.iter_mut() {
+                if !shot.alive {
+                    break;
+                }
+
+                if enemy.alive && shot.hits(&enemy.hitbox) {
+                    let (fx, fy) = shot.prev_xy();
+                    let killed = enemy.take_hit(fx, fy);
+                    shot.register_hit();
+                    audio::play(def.hit_sound);
 
-            if ball_box.intersects(&boss.hitbox) {
-                boss.take_damage(2);
-                ball.alive = false;
+                    if !killed {
+                        continue;
+                    }
+                    match def.kill_alert {
+                        KillAlert::Nearby => pending_alerts.push((enemy.x, enemy.y)),
+                        KillAlert::Everyone => alert_everyone = true,
+                    }
+                }
+            }
+
+            // 📷 One hit knocks a camera out
+            for camera in self.cameras.iter_mut() {
+                if shot.alive && !camera.disabled && shot.hits(&camera.hitbox()) {
+                    camera.disable();
+                    shot.register_hit();
+                    audio::play(def.hit_sound);
+                }
+            }
+
+            // 👹 The boss stops every shot
+            if let Some(boss) = &mut self.boss
+                && shot.alive
+                && shot.hits(&boss.hitbox)
+            {
+                shot.alive = false;
+                match boss.take_damage(def.damage) {
+                    BossHit::Landed => audio::play(def.hit_sound),
+                    BossHit::Blocked => audio::play("block"),
+                    BossHit::Ignored => {}
+                }
             }
         }
-    }
-}
+
+        self.projectiles.retain(|p| p.alive);
+
+        if self.player.health <= 0 {
+            self.flow = GameFlow::Lose;
+            self.lose_timer = 0;
+            audio::play("caught");
+            return;
+        }
+
+        // 🚨 Gun kills wake up every snowman
+        if alert_everyone {
+            self.alert_all_snowmen();
+        }
+
 for (x, y) in pending_alerts {
     self.alert_nearby_snowmen(x, y);
 }
+for (x, y, radius) in pending_noises {
+    self.make_noise(x, y, radius);
+}
+self.noises.retain_mut(|n| n.update());
 
 
-        
-        self.player_snowballs.retain(|b| b.alive);
 
 
         // Update enemies (vision + chase)
+        let hidden = self.player_hidden();
+        let on_alert = self.alarm.level == AlarmLevel::Alarm;
+        let visibility = self.player_visibility();
+
+        // 📷🪤 Cameras and traps raise the same alarm a fallen snowman does
+        let mut alarms: Vec<(f32, f32)> = Vec::new();
+        for camera in self.cameras.iter_mut() {
+            if camera.update(self.player.x, self.player.y, visibility, hidden, &self.map) {
+                alarms.push((self.player.x, self.player.y));
+            }
+        }
+        let sneaking = self.player.gait == Gait::Sneak;
+        for trap in self.traps.iter_mut() {
+            if trap.update(self.player.x + 16.0, self.player.y + 28.0, sneaking) {
+                alarms.push(tile_to_world(trap.tile.0, trap.tile.1));
+            }
+        }
+        for (x, y) in alarms {
+            self.alert_nearby_snowmen(x, y);
+        }
+        // 🧭 Chasers split up instead of queueing down one corridor
+        if self.frame.is_multiple_of(SQUAD_REPLAN_FRAMES) {
+            let exit = if self.exit_open() { self.spawn.exit } else { None };
+            let player_tile = self.player_tile();
+            coordinate_squad(&mut self.enemies, &self.map, player_tile, exit);
+        }
+
+        let mut radio_calls: Vec<((f32, f32), (f32, f32))> = Vec::new();
         for enemy in self.enemies.iter_mut() {
-            if enemy.update(self.player.x, self.player.y, &self.map) {
+            enemy.set_on_alert(on_alert);
+            let attack = enemy.update(
+                self.player.x,
+                self.player.y,
+                visibility,
+                hidden,
+                &self.footprints,
+                &self.map,
+            );
+
+            if let Some(last_seen) = enemy.take_call_out() {
+                radio_calls.push(((enemy.x + 16.0, enemy.y + 16.0), last_seen));
+            }
+
+            if attack {
                 let bx = enemy.x + 16.0;
                 let by = enemy.y + 16.0;
-                let angle = enemy.facing_angle;
-                
-                // 🔫 PLAY SHOOT SOUND (ONCE PER SHOT)
-                audio::play("shoot");
 
-                self.bullets.push(Bullet::new(bx, by, angle));
-                
+                match enemy.def().attack {
+                    EnemyAttack::Shoot(kind) => {
+                        let angle = enemy.facing_angle;
+                        let shot = Projectile::new(kind, bx, by, (angle.cos(), angle.sin()));
+
+                        // 🔫 PLAY SHOOT SOUND (ONCE PER SHOT)
+                        audio::play(shot.def().fire_sound);
+
+                        self.projectiles.push(shot);
+                    }
+                    // 🦌 Hounds only bite what they can reach
+                    EnemyAttack::Bite => {
+                        if self.player.take_damage(1, DamageSource::Hound, (bx, by)) {
+                            audio::play("hit");
+                        }
+                    }
+                }
 
                 if self.player.health <= 0 {
                     self.flow = GameFlow::Lose;
@@ -533,11 +797,26 @@ for (x, y) in pending_alerts {
             }
         }
 
-        for bullet in self.bullets.iter_mut() {
-            bullet.update(&self.map, &mut self.player);
+        for (from, last_seen) in radio_calls {
+            self.radio_call(from, last_seen);
+        }
+
+        // 🚨 Level alarm follows what the snowmen are up to
+        let live = || self.enemies.iter().filter(|e| e.alive);
+        let chasing = live().any(|e| e.state == EnemyState::Chasing);
+        let uneasy = live().any(|e| matches!(e.state, EnemyState::Suspicious | EnemyState::Searching));
+        self.alarm.update(chasing, uneasy, hidden);
+
+        if let Some(level) = self.alarm.take_change() {
+            self.set_music_volume(level.music_volume());
+            if level == AlarmLevel::Alarm {
+                audio::play("alarm");
+            }
+        }
+        if self.alarm.take_reinforcement() && self.boss.is_none() {
+            self.call_reinforcement();
         }
 
-        self.bullets.retain(|b| b.alive);
 
 
 
@@ -560,16 +839,32 @@ if let Some(boss) = &self.boss {
 }
 // No boss yet
 else {
+    // 🚪 Sneaking out the exit clears a non-boss level too
+    if self.exit_open() && self.player_at_exit() {
+        self.advance_level();
+        return;
+    }
+
     // All enemies dead
     if self.enemies.iter().all(|e| !e.alive) {
-        // EVEN LEVEL → spawn boss
-        if self.level % 2 == 0 {
-            if let Some((bx, by)) = find_large_spawn(&self.map, 64.0) {
-                self.boss = Some(Boss::new(bx, by));
+        // BOSS LEVEL → spawn boss
+        if self.level_info.boss {
+            let boss_spawn = match self.spawn.boss {
+                Some((tx, ty)) => Some(tile_to_world(tx, ty)),
+                None => find_large_spawn(&self.map, self.level_info.boss_kind.def().sprite_size),
+            };
+
+            if let Some((bx, by)) = boss_spawn {
+                self.boss = Some(Boss::new(
+                    bx,
+                    by,
+                    self.level_info.boss_kind,
+                    self.level_info.boss_phases,
+                ));
                 audio::play("boss_spawn");
             }
         }
-        // ODD LEVEL → advance immediately
+        // NO BOSS → advance immediately
         else {
             self.advance_level();
             return;
@@ -596,12 +891,31 @@ else {
         cam_x = cam_x.clamp(half_w, map_w - half_w);
         cam_y = cam_y.clamp(half_h, map_h - half_h);
 
+        // 📳 Shake hook – anything that hurt Santa this frame
+        if let Some(amount) = self.player.take_shake() {
+            self.shake_camera(amount);
+        }
+        if self.camera_shake > 0.5 {
+            cam_x += (random::f32() * 2.0 - 1.0) * self.camera_shake;
+            cam_y += (random::f32() * 2.0 - 1.0) * self.camera_shake;
+            self.camera_shake *= 0.85;
+        } else {
+            self.camera_shake = 0.0;
+        }
+
         camera::set_xy(cam_x, cam_y);
 
                 // 👹 UPDATE BOSS (MUST HAPPEN BEFORE DRAWING)
 if let Some(boss) = &mut self.boss {
     boss.update(&mut self.player, &self.map);
 
+    // 🔮 Boss bolts fly with the snowmen's bullets
+    let shots = boss.take_shots();
+    if let Some(shot) = shots.first() {
+        audio::play(shot.def().fire_sound);
+    }
+    self.projectiles.extend(shots);
+
    if boss.is_death_animation_finished() {
     self.advance_level();
     return;
@@ -615,6 +929,15 @@ if let Some(boss) = &mut self.boss {
     }
 }
 
+        // 📣 A new boss phase brings its summons and arena change
+        if let Some(boss) = &mut self.boss
+            && let Some(phase) = boss.take_phase_start()
+        {
+            let (bx, by) = boss.center();
+            self.start_boss_phase(phase, bx, by);
+        }
+        self.phase_banner_timer = self.phase_banner_timer.saturating_sub(1);
+
         
                 // ─── Draw order ───
 
@@ -626,18 +949,33 @@ if let Some(boss) = &mut self.boss {
 
         // 2. Map tiles
         draw_map(&self.map);
+        for print in self.footprints.iter() {
+            print.draw();
+        }
+        for trap in self.traps.iter() {
+            trap.draw();
+        }
         draw_border_christmas_lights(&self.map); // 🎄
-        // 🌲 Decorative corner trees
-        draw_corner_trees(&self.map);
                 //snow piles
         //lamp posts
         draw_lamp_posts(&self.map, self.frame);
+        self.light.draw(); // 🌑 shadows / lamp glow
+        if self.exit_open()
+            && let Some(exit) = self.spawn.exit
+        {
+            draw_exit(exit, self.frame);
+        }
         
         // 🎁 ADD THIS BLOCK ⬇⬇⬇
         for gift in self.gifts.iter() {
             gift.draw();
         }
 
+        // 🔊 Noise ripples
+        for noise in self.noises.iter() {
+            noise.draw();
+        }
+
 
         // 3. Enemies
 if let Some(boss) = &self.boss {
@@ -648,102 +986,35 @@ if let Some(boss) = &self.boss {
 }
 
 
-let mut enemy_killed = false;
-
-for bullet in self.player_bullets.iter_mut() {
-    bullet.update(&self.map);
-
-    if !bullet.alive {
-        continue;
-    }
-
-    let b_box = bullet.hitbox();
-
-    // ❄️ HIT SNOWMEN (ENEMIES)
-    for enemy in self.enemies.iter_mut() {
-    if enemy.alive && b_box.intersects(&enemy.hitbox) {
-    enemy.alive = false;
-    bullet.alive = false;
-    audio::play("hit");
-
-    enemy_killed = true; // ✅ MARK EVENT
-
-    break;
-}
-
-
-    }
-
-    // 👹 HIT BOSS (HIGH DAMAGE)
-    if let Some(boss) = &mut self.boss {
-        if bullet.alive && b_box.intersects(&boss.hitbox) {
-            boss.take_damage(4); // 🔥 MUCH STRONGER THAN SNOWBALL
-            bullet.alive = false;
-            audio::play("hit");
-        }
-    }
-}
-
-self.player_bullets.retain(|b| b.alive);
-// 🚨 GLOBAL ALERT AFTER BULLET LOOP
-if enemy_killed {
-    self.alert_all_snowmen();
-}
-
-for bullet in self.player_bullets.iter() {
-    bullet.draw();
-}
 
         for enemy in self.enemies.iter() {
-                    enemy.draw();
+                    enemy.draw(&self.map, self.settings.show_vision_cones, visibility);
+                }
+                for camera in self.cameras.iter() {
+                    camera.draw(&self.map, self.settings.show_vision_cones, visibility);
                 }
+                self.draw_takedown_prompt();
                 self.draw_health_ui(&self.player);
 
-                for bullet in self.bullets.iter() {
-            bullet.draw();
+                for shot in self.projectiles.iter() {
+            shot.draw();
         }
 
         // 4. Player
         self.player.draw();
-        for ball in self.player_snowballs.iter() {
-            ball.draw();
+
+        // 🌲 Cover goes over Santa so he looks tucked inside
+        for cover in self.covers.iter() {
+            cover.draw();
         }
 
         self.draw_level_banner();
+        self.draw_phase_banner();
         self.draw_health_ui(&self.player);
         self.draw_top_hud();
 
     }
 
-    fn draw_map(&self) {
-        for (y, row) in self.map.iter().enumerate() {
-            for (x, tile) in row.iter().enumerate() {
-                let px = x as i32 * TILE_SIZE;
-                let py = y as i32 * TILE_SIZE;
-
-                match tile.tile_type {
-                    TileType::Wall => {
-                        rect!(
-                            x = px,
-                            y = py,
-                            w = TILE_SIZE as u32,
-                            h = TILE_SIZE as u32,
-                            color = 0x666666ff
-                        );
-                    }
-                    TileType::Floor => {
-                        rect!(
-                            x = px,
-                            y = py,
-                            w = TILE_SIZE as u32,
-                            h = TILE_SIZE as u32,
-                            color = 0x1e1e2eff
-                        );
-                    }
-                }
-            }
-        }
-    }
     fn draw_health_ui(&self, player: &Player) {
         let x = 12;
         let y = 12;
@@ -819,9 +1090,31 @@ fn draw_top_hud(&self) {
         y = 36,
         fixed = true,
         scale = 1.8,
-        color = 0xffffffff
+        color = self.alarm.level.color()
     );
 
+    // ─── LEFT: Gait (only when not walking) ───
+    let gait = match self.player.gait {
+        Gait::Sneak => Some(("SNEAK", 0x88ccffff)),
+        Gait::Sprint => Some(("SPRINT", 0xffaa44ff)),
+        Gait::Walk => None,
+    };
+
+    if let Some((label, color)) = gait {
+        text!(label, x = 13, y = 59, fixed = true, scale = 1.4, color = 0x000000ff);
+        text!(label, x = 12, y = 58, fixed = true, scale = 1.4, color = color);
+    }
+
+    // ─── LEFT: Hidden badge ───
+    if self.player_hidden() {
+        rect!(x = 10, y = 74, w = 64, h = 16, fixed = true, color = 0x0b2a14cc);
+        rect!(x = 10, y = 74, w = 3, h = 16, fixed = true, color = 0x44ff88ff);
+        text!("HIDDEN", x = 18, y = 77, fixed = true, scale = 1.4, color = 0x44ff88ff);
+    }
+
+    // ─── LEFT: Level alarm ───
+    self.alarm.draw(10, 94);
+
     // ─── RIGHT: Level (top-right, always visible) ───
     let level_text = format!("LEVEL {}", self.level);
     let width = level_text.len() as i32 * 8 * 2;
@@ -832,16 +1125,50 @@ fn draw_top_hud(&self) {
         y = 12,
         fixed = true,
         scale = 2.0,
-        color = 0xffffffff
+        color = self.alarm.level.color()
     );
+
+    // ─── RIGHT: Weapon + ammo (under the level) ───
+    let inventory = &self.player.inventory;
+    let slot = inventory.slot();
+    let panel_w = 150;
+    let panel_x = sw - panel_w - 12;
+    let panel_y = 38;
+
+    rect!(x = panel_x, y = panel_y, w = panel_w, h = 40, fixed = true, color = 0x000000aa);
+    text!(slot.weapon.label(), x = panel_x + 8, y = panel_y + 6, fixed = true, scale = 1.4, color = 0xffffffff);
+
+    let ammo_text = match (slot.weapon.projectile(), slot.reserve) {
+        (None, _) => "MELEE".to_string(),
+        (Some(kind), None) => format!("{} / {}", slot.loaded, kind.def().magazine),
+        (Some(kind), Some(reserve)) => {
+            format!("{} / {}  +{}", slot.loaded, kind.def().magazine, reserve)
+        }
+    };
+
+    if inventory.reload_timer > 0 {
+        // Reload bar fills in place of the ammo count
+        let fill = ((panel_w - 16) as f32 * inventory.reload_progress()) as u32;
+        rect!(x = panel_x + 8, y = panel_y + 24, w = (panel_w - 16) as u32, h = 8, fixed = true, color = 0xffffff33);
+        rect!(x = panel_x + 8, y = panel_y + 24, w = fill, h = 8, fixed = true, color = 0xffdd66ff);
+        text!("RELOADING", x = panel_x + 8, y = panel_y + 24, fixed = true, scale = 0.8, color = 0x000000ff);
+    } else {
+        let ammo_color = if slot.loaded == 0 && slot.weapon.projectile().is_some() {
+            0xff5555ff
+        } else {
+            0xffdd66ff
+        };
+        text!(&ammo_text, x = panel_x + 8, y = panel_y + 22, fixed = true, scale = 1.4, color = ammo_color);
+    }
 }
 
 
 
     fn reset_game(&mut self) {
 
-        audio::stop("snowmusic");
+        audio::stop(&self.level_info.music);
 audio::stop("backgroundmusic");
+        self.set_music_volume(AlarmLevel::Calm.music_volume());
 
         let mut new_game = GameState::new();
 
@@ -849,9 +1176,134 @@ audio::stop("backgroundmusic");
         new_game.flow = GameFlow::Playing;
         new_game.start_screen.active = false;
 
+        // ⚙️ Keep the player's preferences
+        new_game.settings = self.settings.clone();
+        new_game.settings.open = false;
+
         *self = new_game;
     }
 
+    /// First snowman Santa can take down right now
+    fn takedown_target(&self) -> Option<usize> {
+        self.enemies
+            .iter()
+            .position(|e| e.can_be_taken_down(self.player.x, self.player.y))
+    }
+
+    /// "E" key hint over the snowman in takedown reach
+    fn draw_takedown_prompt(&self) {
+        let Some(i) = self.takedown_target() else {
+            return;
+        };
+
+        let enemy = &self.enemies[i];
+        let bob = ((self.frame as f32 * 0.15).sin() * 2.0) as i32;
+        let x = enemy.x as i32 + 9;
+        let y = enemy.y as i32 - 30 + bob;
+
+        rect!(x = x, y = y, w = 14, h = 14, color = 0x000000cc);
+        rect!(x = x, y = y + 12, w = 14, h = 2, color = 0x44ff88ff);
+        text!("E", x = x + 4, y = y + 3, scale = 1.0, color = 0xffffffff);
+    }
+
+    /// Applies a boss phase that just started around the boss at (x, y).
+    fn start_boss_phase(&mut self, phase: &'static BossPhase, x: f32, y: f32) {
+        const SUMMON_REACH: usize = 5; // tiles of walking distance from the boss
+        const PHASE_BANNER_FRAMES: u32 = 150;
+
+        // ⛄ Called-in snowmen appear nearby, already hunting Santa
+        let mut boss_tile = ((x as i32 / TILE_SIZE) as usize, (y as i32 / TILE_SIZE) as usize);
+        if self.map[boss_tile.1][boss_tile.0].tile_type == TileType::Wall
+            && let Some(floor) = find_nearest_floor(&self.map, boss_tile)
+        {
+            boss_tile = floor;
+        }
+
+        let mut spots: Vec<(usize, usize)> = tiles_within_steps(&self.map, boss_tile, SUMMON_REACH)
+            .into_iter()
+            .filter(|&(_, steps)| steps >= 2)
+            .map(|(tile, _)| tile)
+            .collect();
+        random::shuffle(&mut spots);
+
+        let mut summoned = 0;
+        for (tx, ty) in spots {
+            if summoned >= phase.summons {
+                break;
+            }
+
+            let (sx, sy) = tile_to_world(tx, ty);
+            if is_spawn_position_valid(&self.map, sx, sy, 32.0) {
+                let mut enemy = Enemy::new(EnemyKind::Snowman, sx, sy);
+                enemy.alert(self.player.x, self.player.y);
+                self.enemies.push(enemy);
+                summoned += 1;
+            }
+        }
+
+        // 🌑 Arena
+        match phase.arena {
+            ArenaChange::Unchanged => {}
+            ArenaChange::Darken(darkness) => self.light = LightMap::new(&self.map, darkness),
+            ArenaChange::ClearCover => self.covers.clear(),
+        }
+
+        self.phase_banner_timer = PHASE_BANNER_FRAMES;
+        self.shake_camera(8.0);
+        audio::play("boss_spawn");
+    }
+
+    /// Jolts the camera; the strongest recent request wins
+    fn shake_camera(&mut self, amount: f32) {
+        self.camera_shake = self.camera_shake.max(amount);
+    }
+
+    /// Launches a shot from (x, y) with the weapon's sound and noise
+    fn fire(
+        &mut self,
+        kind: ProjectileKind,
+        x: f32,
+        y: f32,
+        dir: (f32, f32),
+        pending_noises: &mut Vec<(f32, f32, f32)>,
+    ) {
+        let shot = Projectile::new(kind, x, y, dir);
+        let def = shot.def();
+
+        audio::play(def.fire_sound);
+        if def.fire_noise > 0.0 {
+            pending_noises.push((x, y, def.fire_noise));
+        }
+
+        self.projectiles.push(shot);
+    }
+
+    /// Boss phase name under the boss bar, fading out
+    fn draw_phase_banner(&self) {
+        let Some(boss) = &self.boss else {
+            return;
+        };
+        if self.phase_banner_timer == 0 {
+            return;
+        }
+
+        let sw = screen().w() as f32;
+        let alpha = (self.phase_banner_timer.min(30) as f32 / 30.0 * 255.0) as u32;
+
+        let name = boss.phase().name.to_uppercase();
+        let scale = 2.0;
+        let width = name.len() as f32 * 8.0 * scale;
+
+        text!(
+            &name,
+            x = (sw / 2.0 - width / 2.0) as i32,
+            y = 44,
+            fixed = true,
+            scale = scale,
+            color = 0xc070ff00 | alpha
+        );
+    }
+
     fn draw_level_banner(&self) {
         if self.level_banner_timer == 0 {
             return;
@@ -888,7 +1340,7 @@ audio::stop("backgroundmusic");
 
         let width = text.len() as f32 * 8.0 * scale;
         let x = (sw as f32 / 2.0 - width / 2.0) as i32;
-        let y = sh / 2 - 22;
+        let y = sh / 2 - 36;
 
         text!(
             &text,
@@ -898,6 +1350,20 @@ audio::stop("backgroundmusic");
             scale = scale,
             color = 0xffffffff
         );
+
+        // 🏷 Level name from the level file
+        let name = &self.level_info.name;
+        let name_scale = 1.6;
+        let name_width = name.len() as f32 * 8.0 * name_scale;
+
+        text!(
+            name,
+            x = (sw as f32 / 2.0 - name_width / 2.0) as i32,
+            y = sh / 2 + 2,
+            fixed = true,
+            scale = name_scale,
+            color = 0xffffffcc
+        );
     }
     fn draw_game_world(&self) {
         // 1. Background
@@ -908,36 +1374,55 @@ audio::stop("backgroundmusic");
 
         // 2. Map
         draw_map(&self.map);
+        for print in self.footprints.iter() {
+            print.draw();
+        }
+        for trap in self.traps.iter() {
+            trap.draw();
+        }
         draw_border_christmas_lights(&self.map); 
-        // 🌲 Decorative corner trees
-        draw_corner_trees(&self.map);
         //lamp_posts
         draw_lamp_posts(&self.map, self.frame);
+        self.light.draw(); // 🌑 shadows / lamp glow
+        if self.exit_open()
+            && let Some(exit) = self.spawn.exit
+        {
+            draw_exit(exit, self.frame);
+        }
         
         // 🎁 Gifts
         for gift in self.gifts.iter() {
             gift.draw();
         }
+
+        // 🔊 Noise ripples
+        for noise in self.noises.iter() {
+            noise.draw();
+        }
         
         // 3. Enemies
         if let Some(boss) = &self.boss {
     boss.draw();
 }
+        let visibility = self.player_visibility();
         for enemy in self.enemies.iter() {
-            enemy.draw();
+            enemy.draw(&self.map, self.settings.show_vision_cones, visibility);
+        }
+        for camera in self.cameras.iter() {
+            camera.draw(&self.map, self.settings.show_vision_cones, visibility);
         }
 
-        // 4. Bullets
-        for bullet in self.bullets.iter() {
-            bullet.draw();
+        // 4. Projectiles
+        for shot in self.projectiles.iter() {
+            shot.draw();
         }
 
         // 5. Player
         self.player.draw();
 
-        // 6. Snowballs
-        for ball in self.player_snowballs.iter() {
-            ball.draw();
+        // 🌲 Hiding spots (over Santa)
+        for cover in self.covers.iter() {
+            cover.draw();
         }
 
         // 7. UI (always during gameplay)
@@ -997,16 +1482,68 @@ fn draw_lose_screen(&self) {
         color = 0xffffffff
     );
 
+    // 💀 What got him
+    if let Some(source) = self.player.last_hit {
+        let cause = source.lose_message();
+        let cause_scale = 1.4;
+        let cause_w = cause.len() as f32 * 8.0 * cause_scale;
+
+        text!(
+            cause,
+            x = (sw as f32 / 2.0 - cause_w / 2.0) as i32,
+            y = title_y + 40,
+            fixed = true,
+            scale = cause_scale,
+            color = 0xff8888ff
+        );
+    }
+
     // Retry prompt
     text!(
         "   PRESS SPACE TO RETRY",
         x = sw / 2 - 120,
-        y = title_y + 55,
+        y = title_y + 68,
         fixed = true,
         scale = 1.6,
         color = 0xffffffcc
     );
 }
+    fn player_tile(&self) -> (usize, usize) {
+        (
+            ((self.player.x + 16.0) as i32 / TILE_SIZE) as usize,
+            ((self.player.y + 16.0) as i32 / TILE_SIZE) as usize,
+        )
+    }
+
+    /// How easily Santa is picked out where he stands: gait × light × alarm
+    fn player_visibility(&self) -> f32 {
+        self.player.visibility()
+            * self.light.vision_factor(self.player.x + 16.0, self.player.y + 16.0)
+            * self.alarm.level.vision_factor()
+    }
+
+    /// Santa's centre is inside a tree / present pile
+    fn player_hidden(&self) -> bool {
+        let cx = self.player.x + 16.0;
+        let cy = self.player.y + 16.0;
+
+        self.covers.iter().any(|c| c.contains(cx, cy))
+    }
+
+    /// Exits only count on levels without a boss fight.
+    fn exit_open(&self) -> bool {
+        !self.level_info.boss && self.spawn.exit.is_some()
+    }
+
+    fn player_at_exit(&self) -> bool {
+        let Some((tx, ty)) = self.spawn.exit else {
+            return false;
+        };
+
+        let (ex, ey) = tile_to_world(tx, ty);
+        Bounds::new(ex, ey, TILE_SIZE, TILE_SIZE).intersects(&self.player.hitbox)
+    }
+
     fn snowmen_left(&self) -> usize {
         self.enemies.iter().filter(|e| e.alive).count()
     }
@@ -1032,13 +1569,16 @@ fn draw_boss_health_bar(&self, boss: &Boss) {
         boss.health.max(0) as f32 / boss.max_health as f32;
     let filled_width = (bar_width as f32 * health_ratio) as u32;
 
+    let def = boss.def();
+
     // 👹 BOSS ICON (GIF)
     sprite!(
-        "NightBorne_idle",
+        def.sprites.idle,
         x = icon_x,
         y = icon_y,
         w = icon_size,
         h = icon_size,
+        color = def.tint,
         fixed = true,
         cover = true
     );
@@ -1072,6 +1612,55 @@ fn draw_boss_health_bar(&self, boss: &Boss) {
         fixed = true,
         color = 0xffffff33
     );
+
+    // 🏷 Name on the bar's left edge
+    text!(
+        def.name,
+        x = bar_x as i32 + 4,
+        y = bar_y + 3,
+        fixed = true,
+        scale = 1.0,
+        color = 0xffffffff
+    );
+
+    // 💫 Stagger meter – flashes while the boss is dazed
+    let stagger_w = (bar_width as f32 * boss.stagger_ratio()) as u32;
+    let stagger_color = if boss.is_staggered() && (self.frame / 6).is_multiple_of(2) {
+        0xffffffff
+    } else {
+        0xffd23fff
+    };
+
+    rect!(
+        x = bar_x,
+        y = bar_y + bar_height + 4,
+        w = bar_width,
+        h = 4,
+        fixed = true,
+        color = 0x000000aa
+    );
+    rect!(
+        x = bar_x,
+        y = bar_y + bar_height + 4,
+        w = stagger_w,
+        h = 4,
+        fixed = true,
+        color = stagger_color
+    );
+
+    // 📍 Where the next phases kick in
+    for phase in boss.phases().iter().skip(1) {
+        let tick_x = bar_x as i32 + (bar_width as f32 * phase.health_below) as i32;
+
+        rect!(
+            x = tick_x - 1,
+            y = bar_y - 3,
+            w = 2,
+            h = bar_height + 6,
+            fixed = true,
+            color = 0xffffffcc
+        );
+    }
 }
 
 
@@ -0,0 +1,92 @@
+use turbo::*;
+use crate::{Grid, TILE_SIZE};
+use crate::map::lamp_post_tiles;
+use crate::model::tiles_within_steps;
+
+const LAMP_REACH: usize = 3; // tiles of walkable distance a lamp lights up
+const LAMP_FALLOFF: f32 = 0.2; // light lost per tile away from the lamp
+
+// Vision multiplier at light 0.0 and 1.0 (light 0.5 = normal range)
+const SHADOW_VISION: f32 = 0.6;
+const LIT_VISION: f32 = 1.4;
+
+const MAX_SHADOW_ALPHA: f32 = 170.0;
+const MAX_GLOW_ALPHA: f32 = 50.0;
+
+/// Per-tile light level, 0.0 = pitch black, 1.0 = right under a lamp.
+#[turbo::serialize]
+pub struct LightMap {
+    tiles: Vec<Vec<f32>>,
+    ambient: f32,
+}
+
+impl LightMap {
+    pub fn new(grid: &Grid, darkness: f32) -> Self {
+        let ambient = 1.0 - darkness;
+        let mut tiles = vec![vec![ambient; grid[0].len()]; grid.len()];
+
+        // 💡 Lamps light the corridor around them, not through walls
+        for lamp in lamp_post_tiles(grid) {
+            for ((x, y), steps) in tiles_within_steps(grid, lamp, LAMP_REACH) {
+                let light = 1.0 - steps as f32 * LAMP_FALLOFF;
+                tiles[y][x] = tiles[y][x].max(light);
+            }
+        }
+
+        Self { tiles, ambient }
+    }
+
+    /// Light level at a world point (outside the map counts as ambient).
+    pub fn light_at(&self, x: f32, y: f32) -> f32 {
+        if x < 0.0 || y < 0.0 {
+            return self.ambient;
+        }
+
+        let tx = (x as i32 / TILE_SIZE) as usize;
+        let ty = (y as i32 / TILE_SIZE) as usize;
+
+        self.tiles
+            .get(ty)
+            .and_then(|row| row.get(tx))
+            .copied()
+            .unwrap_or(self.ambient)
+    }
+
+    /// How much further (> 1) or closer (< 1) than normal a snowman has to be
+    /// to pick out someone standing at (x, y).
+    pub fn vision_factor(&self, x: f32, y: f32) -> f32 {
+        SHADOW_VISION + (LIT_VISION - SHADOW_VISION) * self.light_at(x, y)
+    }
+
+    /// Shadow over dark tiles, warm glow over lamp-lit ones.
+    pub fn draw(&self) {
+        for (ty, row) in self.tiles.iter().enumerate() {
+            for (tx, &light) in row.iter().enumerate() {
+                let x = tx as i32 * TILE_SIZE;
+                let y = ty as i32 * TILE_SIZE;
+
+                let shadow = ((1.0 - light) * MAX_SHADOW_ALPHA) as u32;
+                if shadow > 0 {
+                    rect!(
+                        x = x,
+                        y = y,
+                        w = TILE_SIZE as u32,
+                        h = TILE_SIZE as u32,
+                        color = 0x05081800 | shadow
+                    );
+                }
+
+                let glow = ((light - self.ambient).max(0.0) * MAX_GLOW_ALPHA) as u32;
+                if glow > 0 {
+                    rect!(
+                        x = x,
+                        y = y,
+                        w = TILE_SIZE as u32,
+                        h = TILE_SIZE as u32,
+                        color = 0xffcc6600 | glow
+                    );
+                }
+            }
+        }
+    }
+}
@@ -58,9 +58,11 @@ pub fn draw_map(grid: &Grid) {
     }
 }
 
-pub fn draw_corner_trees(grid: &Grid) {
+/// Floor tiles in a corridor corner – where the lamp posts stand
+pub fn lamp_post_tiles(grid: &Grid) -> Vec<(usize, usize)> {
     let h = grid.len();
     let w = grid[0].len();
+    let mut tiles = Vec::new();
 
     for y in 1..h - 1 {
         for x in 1..w - 1 {
@@ -73,75 +75,42 @@ pub fn draw_corner_trees(grid: &Grid) {
             let left  = grid[y][x - 1].tile_type == TileType::Wall;
             let right = grid[y][x + 1].tile_type == TileType::Wall;
 
-            if (up as u8 + down as u8 + left as u8 + right as u8) == 3 {
-                let px = (x as i32) * TILE_SIZE;
-                let py = (y as i32) * TILE_SIZE;
-
-                sprite!(
-                    "decor/decor_tree_christmas",
-                    x = px - TILE_SIZE / 2,
-                    y = py - TILE_SIZE,
-                    w = (TILE_SIZE * 2) as u32,
-                    h = (TILE_SIZE * 2) as u32,
-                    cover = true
-                );
+            // ✅ TRUE CORNER ONLY
+            if (up || down) && (left || right) {
+                tiles.push((x, y));
             }
         }
     }
-}
 
+    tiles
+}
 
 pub fn draw_lamp_posts(grid: &Grid, frame: u32) {
-    let h = grid.len();
-    let w = grid[0].len();
-
     let flicker =
-        ((frame as f32 * 0.05).sin() * 20.0 + 200.0) as u32;
-
-    for y in 1..h - 1 {
-        for x in 1..w - 1 {
-            if grid[y][x].tile_type != TileType::Floor {
-                continue;
-            }
+        ((frame as f32 * 0.05).sin() * 20.0 + 90.0) as u32;
 
-            let up    = grid[y - 1][x].tile_type == TileType::Wall;
-            let down  = grid[y + 1][x].tile_type == TileType::Wall;
-            let left  = grid[y][x - 1].tile_type == TileType::Wall;
-            let right = grid[y][x + 1].tile_type == TileType::Wall;
-
-            // ✅ TRUE CORNER ONLY
-            let is_corner =
-                (up && left) ||
-                (up && right) ||
-                (down && left) ||
-                (down && right);
-
-            if !is_corner {
-                continue;
-            }
-
-            let px = (x as i32) * TILE_SIZE;
-            let py = (y as i32) * TILE_SIZE;
+    for (x, y) in lamp_post_tiles(grid) {
+        let px = (x as i32) * TILE_SIZE;
+        let py = (y as i32) * TILE_SIZE;
 
-            // Lamp
-            sprite!(
-                "decor/decor_lamp_post",
-                x = px + 10,
-                y = py - 18,
-                w = 12,
-                h = 28,
-                cover = true
-            );
+        // Lamp
+        sprite!(
+            "decor/decor_lamp_post",
+            x = px + 10,
+            y = py - 18,
+            w = 12,
+            h = 28,
+            cover = true
+        );
 
-            // Glow
-            rect!(
-                x = px + 6,
-                y = py - 22,
-                w = 20,
-                h = 20,
-                color = (flicker << 24) | 0xffcc00
-            );
-        }
+        // Glow
+        rect!(
+            x = px + 6,
+            y = py - 22,
+            w = 20,
+            h = 20,
+            color = 0xffcc0000 | flicker
+        );
     }
 }
 
@@ -191,3 +160,38 @@ pub fn draw_border_christmas_lights(grid: &Grid) {
         );
     }
 }
+
+
+/// Glowing doorway on the level's exit tile
+pub fn draw_exit((tx, ty): (usize, usize), frame: u32) {
+    let px = (tx as i32) * TILE_SIZE;
+    let py = (ty as i32) * TILE_SIZE;
+
+    let pulse = ((frame as f32 * 0.08).sin() * 40.0 + 120.0) as u32;
+
+    // Glow
+    rect!(
+        x = px,
+        y = py,
+        w = TILE_SIZE as u32,
+        h = TILE_SIZE as u32,
+        color = 0x44ff8800 | pulse
+    );
+
+    // Door frame
+    rect!(
+        x = px + 8,
+        y = py + 4,
+        w = 16,
+        h = 24,
+        color = 0x1a3d26ff
+    );
+
+    text!(
+        "EXIT",
+        x = px + 1,
+        y = py - 10,
+        scale = 1.0,
+        color = 0xffffffff
+    );
+}
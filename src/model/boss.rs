@@ -2,15 +2,28 @@ use turbo::*;
 use crate::{Grid, is_wall, TILE_SIZE};
 use crate::model::{TileType, find_nearest_floor};
 
-use crate::player::Player;
+use crate::player::{DamageSource, Player};
 use crate::model::find_path;
+use crate::model::{BossAttack, BossDef, BossKind, BossPhase, PhasePlan};
+use crate::projectile::Projectile;
 
-const SPRITE_SIZE: f32 = 64.0;
-const COLLISION_SIZE: f32 = 28.0;
-const COLLISION_OFFSET: f32 = (SPRITE_SIZE - COLLISION_SIZE) / 2.0;
-const ATTACK_DURATION: u32 = 35; // frames to fully play NightBorne_attack.gif
-const ATTACK_RANGE: f32 = 50.0; // must match your intended melee reach
-const ATTACK_IMPACT_FRAME: u32 = 20;
+const KNOCKBACK_DECAY: f32 = 0.75; // knockback speed kept each frame
+const PHASE_TRANSITION_FRAMES: u32 = 80; // roar between phases, can't be hurt
+
+// ─── Armour & stagger ───
+const STAGGER_FRAMES: u32 = 75;         // dazed and wide open
+const STAGGER_DAMAGE_BONUS: f32 = 1.5;  // hits on a staggered boss hurt more
+const STAGGER_DECAY: f32 = 0.04;        // meter lost per frame
+const BLOCK_FLASH_FRAMES: u32 = 24;     // "BLOCKED" pop-up
+
+// ─── Ranged / area attacks ───
+const FAN_MIN_RANGE: f32 = 90.0;   // closer than this the lunge is the better bet
+const FAN_MAX_RANGE: f32 = 320.0;
+const SHOCKWAVE_SPEED: f32 = 4.0;  // px/frame the ring grows
+const SHOCKWAVE_BAND: f32 = 12.0;  // how thick the ring is for hits
+const DASH_SPEED: f32 = 8.0;
+const DASH_ALIGN: f32 = 20.0;      // Santa must be this close to the corridor's axis
+const DASH_MIN_LENGTH: f32 = 96.0; // no point charging less than 3 tiles
 
 
 #[turbo::serialize]
@@ -20,6 +33,10 @@ pub enum BossState {
     Chasing,
     Attacking,
     Hurt,
+    Windup,  // telegraphing a ranged / area attack, still vulnerable
+    Dashing,
+    Staggered, // attack broken, wide open
+    PhaseChange,
     Dead,
 }
 
@@ -42,23 +59,68 @@ pub struct Boss {
     path_index: usize,
     repath_timer: u32,
     death_timer: u32,
-    pub can_take_damage: bool,
     pub attack_timer: u32, 
+    knockback: (f32, f32), // px/frame, decays to zero
+
+    pub kind: BossKind,
+
+    // ─── Phases ───
+    plan: PhasePlan,
+    phase: usize,            // index into `plan.phases()`
+    transition_timer: u32,   // > 0 → roaring into the current phase
+    phase_started: bool,     // picked up by GameState (summons, arena)
+
+    // ─── Telegraphed attacks ───
+    windup: Option<BossAttack>,
+    windup_timer: u32,
+    aim: (f32, f32),            // unit vector, locked when the windup starts
+    dash_end: (f32, f32),       // where the charge stops (top-left)
+    dash_left: f32,             // px of the charge still to run
+    dash_hit: bool,             // one hit per charge
+    shockwave: Option<Shockwave>,
+    pending_shots: Vec<Projectile>, // picked up by GameState
+
+    // ─── Armour & stagger ───
+    pub stagger_meter: f32,    // fills with every hit, full → staggered
+    stagger_timer: u32,
+    block_flash: u32,          // > 0 → a hit just bounced off
+}
+
+/// What a hit on the boss did.
+#[derive(Clone, Copy, PartialEq)]
+pub enum BossHit {
+    Landed,
+    Blocked, // armour up – no damage, but it builds stagger
+    Ignored, // already dead
+}
+
+/// A ring rolling out from where the boss slammed the ground.
+#[turbo::serialize]
+pub struct Shockwave {
+    x: f32,
+    y: f32,
+    radius: f32,
+    max_radius: f32,
+    hit: bool,
 }
 
 impl Boss {
 
 
    
-    pub fn new(x: f32, y: f32) -> Self {
+    /// `plan` overrides the boss's own phases when a level asks for it.
+    pub fn new(x: f32, y: f32, kind: BossKind, plan: Option<PhasePlan>) -> Self {
+        let def = kind.def();
+        let offset = (def.sprite_size - def.collision_size) / 2.0;
+
         Self {
             x,
             y,
             hitbox: Bounds::new(
-    x + COLLISION_OFFSET,
-    y + COLLISION_OFFSET,
-    COLLISION_SIZE as u32,
-    COLLISION_SIZE as u32,
+    x + offset,
+    y + offset,
+    def.collision_size as u32,
+    def.collision_size as u32,
    
 
 ),
@@ -66,8 +128,8 @@ impl Boss {
 
 
             state: BossState::Idle,
-            health: 120,
-            max_health: 120,
+            health: def.max_health,
+            max_health: def.max_health,
 
             facing_x: 1.0,
             attack_cooldown: 0,
@@ -78,8 +140,24 @@ impl Boss {
             path_index: 0,
             repath_timer: 1,
             death_timer: 0,
-            can_take_damage: true, // boss starts vulnerable
             attack_timer: 0,
+            knockback: (0.0, 0.0),
+            kind,
+            plan: plan.unwrap_or(def.phases),
+            phase: 0,
+            transition_timer: 0,
+            phase_started: false,
+            windup: None,
+            windup_timer: 0,
+            aim: (1.0, 0.0),
+            dash_end: (x, y),
+            dash_left: 0.0,
+            dash_hit: false,
+            shockwave: None,
+            pending_shots: Vec::new(),
+            stagger_meter: 0.0,
+            stagger_timer: 0,
+            block_flash: 0,
 
 
 
@@ -90,32 +168,81 @@ impl Boss {
     pub fn update(&mut self, player: &mut Player, map: &Grid) {
         if self.state == BossState::Dead {
     self.death_timer = self.death_timer.saturating_sub(1);
+    self.shockwave = None;
     return;
 }
 
+        self.update_shockwave(player, map);
+        self.block_flash = self.block_flash.saturating_sub(1);
+
+
+        // 📣 Roaring into a new phase – stands still until it's done
+        if self.state == BossState::PhaseChange {
+            self.transition_timer = self.transition_timer.saturating_sub(1);
+            if self.transition_timer == 0 {
+                self.state = BossState::Chasing;
+            }
+            return;
+        }
 
         self.attack_cooldown = self.attack_cooldown.saturating_sub(1);
         self.hurt_timer = self.hurt_timer.saturating_sub(1);
 
+        // 🍬 Slide from a knockback hit
+        if self.knockback.0.abs() > 0.1 || self.knockback.1.abs() > 0.1 {
+            let (kx, ky) = self.knockback;
+
+            if self.can_move(map, self.x + kx + self.collision_offset(), self.y + self.collision_offset()) {
+                self.x += kx;
+            }
+            if self.can_move(map, self.x + self.collision_offset(), self.y + ky + self.collision_offset()) {
+                self.y += ky;
+            }
+
+            self.knockback = (kx * KNOCKBACK_DECAY, ky * KNOCKBACK_DECAY);
+        }
+
+        // 💫 Staggered – dazed and wide open until it wears off
+        if self.state == BossState::Staggered {
+            self.stagger_timer = self.stagger_timer.saturating_sub(1);
+            if self.stagger_timer == 0 {
+                self.state = BossState::Chasing;
+                self.path.clear();
+            }
+
+            self.hitbox = Bounds::new(
+                self.x + self.collision_offset(),
+                self.y + self.collision_offset(),
+                self.def().collision_size as u32,
+                self.def().collision_size as u32,
+            );
+            return;
+        }
+
+        self.stagger_meter = (self.stagger_meter - STAGGER_DECAY).max(0.0);
+
         // ─── HANDLE ATTACK ANIMATION (FULL COMMIT) ───
-if self.state == BossState::Attacking {
-    if self.attack_timer > 0 {
+if self.state == BossState::Attacking && self.attack_timer > 0 {
         self.attack_timer -= 1;
 
        // 💥 ATTACK IMPACT (MID ANIMATION)
-if self.attack_timer == ATTACK_IMPACT_FRAME {
-    let dx = (player.x + 16.0) - (self.x + SPRITE_SIZE / 2.0);
-    let dy = (player.y + 16.0) - (self.y + SPRITE_SIZE / 2.0);
+if self.attack_timer == self.def().attack_impact_frame {
+    let (cx, cy) = self.center();
+    let dx = (player.x + 16.0) - cx;
+    let dy = (player.y + 16.0) - cy;
     let dist = (dx * dx + dy * dy).sqrt();
 
-    if dist <= ATTACK_RANGE {
-        player.health -= 1;
+    if dist <= self.def().attack_range {
+        player.take_damage(
+            self.def().melee_damage,
+            DamageSource::Boss(self.kind),
+            (cx, cy),
+        );
     }
 }
 
 // 🧘 END OF ATTACK (RECOVERY ONLY)
 if self.attack_timer == 0 {
-    self.can_take_damage = true;
     self.state = BossState::Chasing;
 }
 
@@ -123,37 +250,48 @@ if self.attack_timer == 0 {
 
         // 🩸 ATTACK LUNGE — boss slides forward during attack
 let dx = self.facing_x;
-let lunge_speed = if self.health < self.max_health / 2 { 2.8 } else { 2.0 };
+let lunge_speed = self.phase().lunge_speed;
 
 let try_x = self.x + dx * lunge_speed;
 if self.can_move(
     map,
-    try_x + COLLISION_OFFSET,
-    self.y + COLLISION_OFFSET,
+    try_x + self.collision_offset(),
+    self.y + self.collision_offset(),
 ) {
     self.x = try_x;
 }
 
 // Update hitbox during attack
 self.hitbox = Bounds::new(
-    self.x + COLLISION_OFFSET,
-    self.y + COLLISION_OFFSET,
-    COLLISION_SIZE as u32,
-    COLLISION_SIZE as u32,
+    self.x + self.collision_offset(),
+    self.y + self.collision_offset(),
+    self.def().collision_size as u32,
+    self.def().collision_size as u32,
 );
 
 return;
 
-    }
 }
 
 
+        // ⚠️ Telegraph, then let it go
+        if self.state == BossState::Windup {
+            self.windup_timer = self.windup_timer.saturating_sub(1);
+            if self.windup_timer == 0 {
+                self.release_attack();
+            }
+            return;
+        }
+
+        // 💨 Charging down the corridor
+        if self.state == BossState::Dashing {
+            self.update_dash(player, map);
+            return;
+        }
+
         let dx = player.x - self.x;
-        let dy = player.y - self.y;
-        let dist = (dx * dx + dy * dy).sqrt();
 
-        // 🔥 Enrage phase when boss below 50% HP
-let enraged = self.health < self.max_health / 2;
+        let phase = self.phase();
 
 
         // Face Santa
@@ -165,8 +303,8 @@ let enraged = self.health < self.max_health / 2;
 
 self.repath_timer = self.repath_timer.saturating_sub(1);
 
-let boss_cx = self.x + COLLISION_OFFSET + COLLISION_SIZE / 2.0;
-let boss_cy = self.y + COLLISION_OFFSET + COLLISION_SIZE / 2.0;
+let boss_cx = self.x + self.collision_offset() + self.def().collision_size / 2.0;
+let boss_cy = self.y + self.collision_offset() + self.def().collision_size / 2.0;
 
 let player_cx = player.x + 16.0;
 let player_cy = player.y + 16.0;
@@ -184,22 +322,22 @@ if map[player_tile.1][player_tile.0].tile_type == TileType::Wall {
 
 
 // Recalculate path periodically
-if self.repath_timer == 0 || self.path.is_empty() {
-    if let Some(new_path) = find_path(map, boss_tile, player_tile) {
-        self.path = new_path;
-        self.path_index = 0;
-        self.repath_timer = 30;
-    }
+if (self.repath_timer == 0 || self.path.is_empty())
+    && let Some(new_path) = find_path(map, boss_tile, player_tile)
+{
+    self.path = new_path;
+    self.path_index = 0;
+    self.repath_timer = 30;
 }
 
 // Follow the path
 if self.path_index < self.path.len() {
     let (tx, ty) = self.path[self.path_index];
-    let (target_x, target_y) = tile_target(tx, ty);
+    let (target_x, target_y) = tile_target(tx, ty, self.collision_offset());
 
 
-let cx = self.x + COLLISION_OFFSET + COLLISION_SIZE / 2.0;
-let cy = self.y + COLLISION_OFFSET + COLLISION_SIZE / 2.0;
+let cx = self.x + self.collision_offset() + self.def().collision_size / 2.0;
+let cy = self.y + self.collision_offset() + self.def().collision_size / 2.0;
 
 let dx = target_x - cx;
 let dy = target_y - cy;
@@ -210,14 +348,14 @@ let dy = target_y - cy;
 
     self.path_index += 1;
 } else {
-    let speed = 2.1;
+    let speed = phase.move_speed;
     let step_x = dx / dist * speed;
     let step_y = dy / dist * speed;
 
   if self.can_move(
     map,
-    self.x + step_x + COLLISION_OFFSET,
-    self.y + COLLISION_OFFSET,
+    self.x + step_x + self.collision_offset(),
+    self.y + self.collision_offset(),
 ) {
     self.x += step_x;
 }
@@ -225,8 +363,8 @@ let dy = target_y - cy;
 
   if self.can_move(
     map,
-    self.x + COLLISION_OFFSET,
-    self.y + step_y + COLLISION_OFFSET,
+    self.x + self.collision_offset(),
+    self.y + step_y + self.collision_offset(),
 ) {
     self.y += step_y;
 }
@@ -256,18 +394,22 @@ else if self.hurt_timer > 0 {
 }
 
 
-// 3️⃣ Too far → must chase first
-else if dist > ATTACK_RANGE {
-    self.state = BossState::Chasing;
-}
+// 3️⃣ Any move from this phase's set that fits where Santa is
+else if self.attack_cooldown == 0
+    && let Some(attack) = self.pick_attack(phase, player, map)
+{
+    self.attack_cooldown = phase.attack_cooldown;
 
-else if self.attack_cooldown == 0 {
-    self.state = BossState::Attacking;
-    self.attack_timer = ATTACK_DURATION;
-    self.attack_cooldown = if self.health < self.max_health / 2 { 30 } else { 45 };
+    match attack {
+        BossAttack::Lunge => {
+            self.state = BossState::Attacking;
+            self.attack_timer = self.def().attack_duration;
 
-    // 🔒 LOCK FACING DIRECTION
-    self.facing_x = (player.x - self.x).signum();
+            // 🔒 LOCK FACING DIRECTION
+            self.facing_x = (player.x - self.x).signum();
+        }
+        _ => self.start_windup(attack, player, map),
+    }
 }
 
 
@@ -283,38 +425,337 @@ else {
         }
 
         self.hitbox = Bounds::new(
-    self.x + COLLISION_OFFSET,
-    self.y + COLLISION_OFFSET,
-    COLLISION_SIZE as u32,
-    COLLISION_SIZE as u32,
+    self.x + self.collision_offset(),
+    self.y + self.collision_offset(),
+    self.def().collision_size as u32,
+    self.def().collision_size as u32,
 );
 
     }
 
-   pub fn take_damage(&mut self, dmg: i32) {
+   pub fn take_damage(&mut self, dmg: i32) -> BossHit {
     if self.state == BossState::Dead {
-        return;
+        return BossHit::Ignored;
     }
 
-    // ❌ Cannot take damage during attack
-    if self.state == BossState::Attacking {
-        return;
+    // 🛡 Armoured – the hit bounces off but still builds stagger
+    if self.is_armoured() {
+        self.block_flash = BLOCK_FLASH_FRAMES;
+
+        if self.state != BossState::PhaseChange {
+            self.build_stagger(dmg);
+        }
+        return BossHit::Blocked;
     }
 
+    let dmg = if self.state == BossState::Staggered {
+        (dmg as f32 * STAGGER_DAMAGE_BONUS).round() as i32
+    } else {
+        dmg
+    };
+
     self.health -= dmg;
 
-    // 🎭 Visual hurt reaction ONLY
+    // 🎭 Visual hurt reaction ONLY – a windup or stagger keeps going
     self.hurt_timer = 10;
-    self.state = BossState::Hurt;
+    if self.state != BossState::Windup && self.state != BossState::Staggered {
+        self.state = BossState::Hurt;
+    }
 
     if self.health <= 0 {
         self.health = 0;
         self.state = BossState::Dead;
-        self.death_timer = 90;
+        self.death_timer = self.def().death_frames;
+        return BossHit::Landed;
+    }
+
+    if self.state != BossState::Staggered {
+        self.build_stagger(dmg);
+    }
+
+    // 📉 Crossed into a later phase? (a big hit can skip one)
+    let ratio = self.health as f32 / self.max_health as f32;
+    let reached = self
+        .plan
+        .phases()
+        .iter()
+        .rposition(|phase| ratio < phase.health_below)
+        .unwrap_or(0);
+
+    if reached > self.phase {
+        self.phase = reached;
+        self.state = BossState::PhaseChange;
+        self.transition_timer = PHASE_TRANSITION_FRAMES;
+        self.hurt_timer = 0;
+        self.knockback = (0.0, 0.0);
+        self.windup = None;
+        self.stagger_meter = 0.0;
+        self.phase_started = true;
+    }
+
+    BossHit::Landed
+}
+
+/// Mid-attack, mid-charge or roaring into a phase – hits bounce off.
+pub fn is_armoured(&self) -> bool {
+    matches!(self.state, BossState::Attacking | BossState::Dashing | BossState::PhaseChange)
+}
+
+/// Fills the stagger meter; a full one breaks whatever the boss was doing.
+fn build_stagger(&mut self, dmg: i32) {
+    self.stagger_meter += dmg as f32;
+
+    if self.stagger_meter < self.def().stagger_threshold {
+        return;
+    }
+
+    self.stagger_meter = 0.0;
+    self.state = BossState::Staggered;
+    self.stagger_timer = STAGGER_FRAMES;
+    self.attack_timer = 0;
+    self.windup = None;
+    self.hurt_timer = 0;
+}
+
+/// 0.0 → 1.0 towards the next stagger (stays full while staggered)
+pub fn stagger_ratio(&self) -> f32 {
+    if self.state == BossState::Staggered {
+        return 1.0;
+    }
+
+    (self.stagger_meter / self.def().stagger_threshold).min(1.0)
+}
+
+pub fn is_staggered(&self) -> bool {
+    self.state == BossState::Staggered
+}
+
+/// Bolts fired since the last call – they join the enemy bullets
+pub fn take_shots(&mut self) -> Vec<Projectile> {
+    std::mem::take(&mut self.pending_shots)
+}
+
+pub fn def(&self) -> &'static BossDef {
+    self.kind.def()
+}
+
+fn collision_offset(&self) -> f32 {
+    (self.def().sprite_size - self.def().collision_size) / 2.0
+}
+
+pub fn center(&self) -> (f32, f32) {
+    (self.x + self.def().sprite_size / 2.0, self.y + self.def().sprite_size / 2.0)
+}
+
+/// Random move from the phase's set among those that make sense from here.
+fn pick_attack(&self, phase: &BossPhase, player: &Player, map: &Grid) -> Option<BossAttack> {
+    let (cx, cy) = self.center();
+    let (px, py) = (player.x + 16.0, player.y + 16.0);
+    let centre_dist = ((px - cx).powi(2) + (py - cy).powi(2)).sqrt();
+
+    let usable: Vec<BossAttack> = phase
+        .attacks
+        .iter()
+        .copied()
+        .filter(|attack| match *attack {
+            BossAttack::Lunge => centre_dist <= self.def().attack_range,
+            BossAttack::Fan { .. } => {
+                (FAN_MIN_RANGE..=FAN_MAX_RANGE).contains(&centre_dist)
+                    && clear_line(map, cx, cy, px, py)
+            }
+            BossAttack::Shockwave { radius } => centre_dist <= radius * 0.8,
+            BossAttack::Dash { tiles } => {
+                let aligned = (px - cx).abs() < DASH_ALIGN || (py - cy).abs() < DASH_ALIGN;
+                let reach = tiles as f32 * TILE_SIZE as f32;
+
+                aligned
+                    && centre_dist <= reach
+                    && self.dash_length(map, axis_towards(px - cx, py - cy), reach) >= DASH_MIN_LENGTH
+            }
+        })
+        .collect();
+
+    if usable.is_empty() {
+        return None;
+    }
+
+    Some(usable[random::u32() as usize % usable.len()])
+}
+
+/// Locks the aim and starts the warning.
+fn start_windup(&mut self, attack: BossAttack, player: &Player, map: &Grid) {
+    let (cx, cy) = self.center();
+    let dx = player.x + 16.0 - cx;
+    let dy = player.y + 16.0 - cy;
+    let len = (dx * dx + dy * dy).sqrt().max(0.001);
+
+    self.aim = (dx / len, dy / len);
+
+    if let BossAttack::Dash { tiles } = attack {
+        self.aim = axis_towards(dx, dy);
+        let length = self.dash_length(map, self.aim, tiles as f32 * TILE_SIZE as f32);
+        self.dash_end = (self.x + self.aim.0 * length, self.y + self.aim.1 * length);
+        self.dash_left = length;
     }
+
+    // The telegraph is drawn from here – nothing shoves it off it now
+    self.knockback = (0.0, 0.0);
+
+    if dx.abs() > 1.0 {
+        self.facing_x = dx.signum();
+    }
+
+    self.state = BossState::Windup;
+    self.windup = Some(attack);
+    self.windup_timer = attack.windup_frames();
 }
 
+fn release_attack(&mut self) {
+    let Some(attack) = self.windup.take() else {
+        self.state = BossState::Chasing;
+        return;
+    };
+    let (cx, cy) = self.center();
 
+    self.state = BossState::Chasing;
+
+    match attack {
+        BossAttack::Lunge => {}
+        BossAttack::Fan { shots, spread } => {
+            for angle in fan_angles(self.aim, shots, spread) {
+                self.pending_shots.push(Projectile::new(
+                    self.def().bolt,
+                    cx,
+                    cy,
+                    (angle.cos(), angle.sin()),
+                ));
+            }
+        }
+        BossAttack::Shockwave { radius } => {
+            self.shockwave = Some(Shockwave { x: cx, y: cy, radius: 0.0, max_radius: radius, hit: false });
+        }
+        BossAttack::Dash { .. } => {
+            self.state = BossState::Dashing;
+            self.dash_hit = false;
+        }
+    }
+}
+
+fn update_dash(&mut self, player: &mut Player, map: &Grid) {
+    let step = self.dash_left.min(DASH_SPEED);
+    self.dash_left -= step;
+
+    let nx = self.x + self.aim.0 * step;
+    let ny = self.y + self.aim.1 * step;
+    let blocked = !self.can_move(map, nx + self.collision_offset(), ny + self.collision_offset());
+
+    if !blocked {
+        self.x = nx;
+        self.y = ny;
+    }
+
+    self.hitbox = Bounds::new(
+        self.x + self.collision_offset(),
+        self.y + self.collision_offset(),
+        self.def().collision_size as u32,
+        self.def().collision_size as u32,
+    );
+
+    // 💥 Run Santa over once
+    if !self.dash_hit && self.hitbox.intersects(&player.hitbox) {
+        self.dash_hit = true;
+        player.take_damage(self.def().melee_damage, DamageSource::Boss(self.kind), self.center());
+    }
+
+    if blocked || self.dash_left <= 0.0 {
+        self.state = BossState::Chasing;
+        self.path.clear();
+    }
+}
+
+/// How far (px) the boss can charge along `dir` before a wall, up to `max`.
+fn dash_length(&self, map: &Grid, dir: (f32, f32), max: f32) -> f32 {
+    let mut length = 0.0;
+
+    while length + 4.0 <= max
+        && self.can_move(
+            map,
+            self.x + dir.0 * (length + 4.0) + self.collision_offset(),
+            self.y + dir.1 * (length + 4.0) + self.collision_offset(),
+        )
+    {
+        length += 4.0;
+    }
+
+    length
+}
+
+fn update_shockwave(&mut self, player: &mut Player, map: &Grid) {
+    let Some(wave) = &mut self.shockwave else {
+        return;
+    };
+
+    wave.radius += SHOCKWAVE_SPEED;
+
+    let (px, py) = (player.x + 16.0, player.y + 16.0);
+    let dist = ((px - wave.x).powi(2) + (py - wave.y).powi(2)).sqrt();
+
+    // Walls soak it up – hiding behind one is the answer
+    if !wave.hit
+        && (dist - wave.radius).abs() <= SHOCKWAVE_BAND
+        && clear_line(map, wave.x, wave.y, px, py)
+    {
+        wave.hit = true;
+        player.take_damage(1, DamageSource::Boss(self.kind), (wave.x, wave.y));
+    }
+
+    if wave.radius >= wave.max_radius {
+        self.shockwave = None;
+    }
+}
+
+pub fn phase(&self) -> &'static BossPhase {
+    &self.plan.phases()[self.phase]
+}
+
+pub fn phases(&self) -> &'static [BossPhase] {
+    self.plan.phases()
+}
+
+/// The phase that just started, once – GameState applies its summons
+/// and arena change.
+pub fn take_phase_start(&mut self) -> Option<&'static BossPhase> {
+    if !self.phase_started {
+        return None;
+    }
+
+    self.phase_started = false;
+    Some(self.phase())
+}
+
+/// 0.0 → 1.0 through the phase-change roar
+pub fn transition_progress(&self) -> Option<f32> {
+    if self.state != BossState::PhaseChange {
+        return None;
+    }
+
+    Some(1.0 - self.transition_timer as f32 / PHASE_TRANSITION_FRAMES as f32)
+}
+
+
+
+/// Shoves the boss along (dx, dy) – ignored while armoured, like damage,
+/// and while winding up so a dash stays in its telegraphed lane.
+pub fn knock_back(&mut self, dx: f32, dy: f32, strength: f32) {
+    if matches!(self.state, BossState::Dead | BossState::Windup) || self.is_armoured() {
+        return;
+    }
+
+    let len = (dx * dx + dy * dy).sqrt();
+    if len > 0.0 {
+        self.knockback = (dx / len * strength, dy / len * strength);
+    }
+}
 
 pub fn is_death_animation_finished(&self) -> bool {
     self.state == BossState::Dead && self.death_timer == 0
@@ -324,26 +765,71 @@ pub fn is_death_animation_finished(&self) -> bool {
 
     pub fn draw(&self) {
        
-        let anim = animation::get("nightborne");
+        let def = self.def();
+        let anim = animation::get("boss");
 
      match self.state {
-    BossState::Idle => anim.use_sprite("NightBorne_idle"),
-    BossState::Chasing => anim.use_sprite("NightBorne_run"),
-    BossState::Attacking => anim.use_sprite("NightBorne_attack"),
-    BossState::Hurt => anim.use_sprite("NightBorne_hurt"),
-    BossState::Dead => anim.use_sprite("NightBorne_death"),
+    BossState::Idle => anim.use_sprite(def.sprites.idle),
+    BossState::Chasing => anim.use_sprite(def.sprites.run),
+    BossState::Attacking => anim.use_sprite(def.sprites.attack),
+    BossState::Hurt => anim.use_sprite(def.sprites.hurt),
+    BossState::Windup => anim.use_sprite(def.sprites.idle),
+    BossState::Dashing => anim.use_sprite(def.sprites.run),
+    BossState::Staggered => anim.use_sprite(def.sprites.hurt),
+    BossState::PhaseChange => anim.use_sprite(def.sprites.idle),
+    BossState::Dead => anim.use_sprite(def.sprites.death),
 }
 
 
 
 
+        self.draw_telegraph();
+        self.draw_shockwave();
+
+        // 📣 Phase change: dark rings burst out, the boss pulses red
+        let mut tint = def.tint;
+
+        if let Some(t) = self.transition_progress() {
+            let (cx, cy) = self.center();
+            let (cx, cy) = (cx as i32, cy as i32);
+
+            for ring in 0..3 {
+                let r = ((t * 3.0 + ring as f32 * 0.33) % 1.0) * 90.0;
+                let alpha = ((1.0 - r / 90.0) * 200.0) as u32;
+
+                circ!(
+                    x = cx - r as i32,
+                    y = cy - r as i32,
+                    d = (r * 2.0) as u32,
+                    color = 0x00000000,
+                    border_size = 2,
+                    border_color = 0x9a30ff00 | alpha
+                );
+            }
+
+            if (self.transition_timer / 6).is_multiple_of(2) {
+                tint = 0xff7070ff;
+            }
+        }
+
+        // Fades out over the last half second of the death animation
+        let opacity = if self.state == BossState::Dead {
+            (self.death_timer as f32 / 30.0).min(1.0)
+        } else {
+            1.0
+        };
+
+        self.draw_armour();
+
         sprite!(
-            animation_key = "nightborne",
+            animation_key = "boss",
             x = self.x as i32,
             y = self.y as i32,
-            w = 64,
-            h = 64,
+            w = def.sprite_size as u32,
+            h = def.sprite_size as u32,
             flip_x = self.facing_x < 0.0,
+            color = tint,
+            opacity = opacity,
             cover = true
         );
   
@@ -353,9 +839,144 @@ pub fn is_death_animation_finished(&self) -> bool {
     }
 
 
+    /// ⚠️ Where the coming attack will land, brighter as it gets closer
+    fn draw_telegraph(&self) {
+        let Some(attack) = self.windup else {
+            return;
+        };
+
+        let total = attack.windup_frames().max(1) as f32;
+        let t = 1.0 - self.windup_timer as f32 / total;
+        let alpha = (60.0 + t * 160.0) as u32;
+        let (cx, cy) = self.center();
+
+        match attack {
+            BossAttack::Lunge => {}
+            BossAttack::Fan { shots, spread } => {
+                for angle in fan_angles(self.aim, shots, spread) {
+                    let reach = 40.0 + t * 120.0;
+                    path!(
+                        start = (cx as i32, cy as i32),
+                        end = ((cx + angle.cos() * reach) as i32, (cy + angle.sin() * reach) as i32),
+                        size = 2,
+                        color = 0xff303000 | alpha
+                    );
+                }
+            }
+            BossAttack::Shockwave { radius } => {
+                // Outer edge right away, inner fill grows to meet it
+                circ!(
+                    x = (cx - radius) as i32,
+                    y = (cy - radius) as i32,
+                    d = (radius * 2.0) as u32,
+                    color = 0xff303000 | (alpha / 5),
+                    border_size = 2,
+                    border_color = 0xff303000 | alpha
+                );
+
+                let inner = radius * t;
+                circ!(
+                    x = (cx - inner) as i32,
+                    y = (cy - inner) as i32,
+                    d = (inner * 2.0) as u32,
+                    color = 0xff303000 | (alpha / 3)
+                );
+            }
+            BossAttack::Dash { .. } => {
+                // Lane from here to the stopping point
+                let (ex, ey) = self.dash_end;
+                let left = self.x.min(ex) + self.collision_offset() - 4.0;
+                let top = self.y.min(ey) + self.collision_offset() - 4.0;
+                let w = (ex - self.x).abs() + self.def().collision_size + 8.0;
+                let h = (ey - self.y).abs() + self.def().collision_size + 8.0;
+
+                rect!(
+                    x = left as i32,
+                    y = top as i32,
+                    w = w as u32,
+                    h = h as u32,
+                    color = 0xff303000 | (alpha / 2)
+                );
+            }
+        }
+    }
+
+    /// 🛡 Shield ring while armoured, a spark + "BLOCKED" when a hit bounces,
+    /// circling stars while staggered
+    fn draw_armour(&self) {
+        let (cx, cy) = self.center();
+        let r = self.def().collision_size * 0.5 + 14.0;
+
+        if self.is_armoured() {
+            let pulse = ((time::tick() as f32 * 0.2).sin() * 0.5 + 0.5) * 80.0;
+
+            circ!(
+                x = (cx - r) as i32,
+                y = (cy - r) as i32,
+                d = (r * 2.0) as u32,
+                color = 0x9fd8ff00 | (pulse as u32 / 4),
+                border_size = 2,
+                border_color = 0x9fd8ff00 | (120 + pulse as u32)
+            );
+        }
+
+        if self.block_flash > 0 {
+            let t = self.block_flash as f32 / BLOCK_FLASH_FRAMES as f32;
+            let burst = r + (1.0 - t) * 10.0;
+
+            circ!(
+                x = (cx - burst) as i32,
+                y = (cy - burst) as i32,
+                d = (burst * 2.0) as u32,
+                color = 0x00000000,
+                border_size = 3,
+                border_color = 0xffffff00 | (t * 255.0) as u32
+            );
+
+            text!(
+                "BLOCKED",
+                x = cx as i32 - 21,
+                y = (self.y - 10.0 - (1.0 - t) * 12.0) as i32,
+                scale = 1.0,
+                color = 0xdde8ff00 | (t * 255.0) as u32
+            );
+        }
+
+        if self.state == BossState::Staggered {
+            let spin = time::tick() as f32 * 0.15;
+
+            for i in 0..3 {
+                let a = spin + i as f32 * std::f32::consts::TAU / 3.0;
+                circ!(
+                    x = (cx + a.cos() * 14.0) as i32 - 3,
+                    y = (self.y + 4.0 + a.sin() * 4.0) as i32 - 3,
+                    d = 6,
+                    color = 0xffe066ff
+                );
+            }
+        }
+    }
+
+    fn draw_shockwave(&self) {
+        let Some(wave) = &self.shockwave else {
+            return;
+        };
+
+        let alpha = ((1.0 - wave.radius / wave.max_radius) * 220.0) as u32;
+
+        circ!(
+            x = (wave.x - wave.radius) as i32,
+            y = (wave.y - wave.radius) as i32,
+            d = (wave.radius * 2.0) as u32,
+            color = 0x00000000,
+            border_size = 4,
+            border_color = 0xd8e8ff00 | alpha
+        );
+    }
+
     fn can_move(&self, map: &Grid, x: f32, y: f32) -> bool {
-    let w = COLLISION_SIZE;
-let h = COLLISION_SIZE;
+    let w = self.def().collision_size;
+let h = self.def().collision_size;
 
 
     let points = [
@@ -383,9 +1004,44 @@ fn world_to_tile(x: f32, y: f32) -> (usize, usize) {
     )
 }
 
-fn tile_target(tx: usize, ty: usize) -> (f32, f32) {
+fn tile_target(tx: usize, ty: usize, offset: f32) -> (f32, f32) {
     (
-        tx as f32 * TILE_SIZE as f32 + COLLISION_OFFSET,
-        ty as f32 * TILE_SIZE as f32 + COLLISION_OFFSET,
+        tx as f32 * TILE_SIZE as f32 + offset,
+        ty as f32 * TILE_SIZE as f32 + offset,
     )
 }
+
+/// Unit vector along whichever axis points more towards (dx, dy)
+fn axis_towards(dx: f32, dy: f32) -> (f32, f32) {
+    if dx.abs() >= dy.abs() {
+        (dx.signum(), 0.0)
+    } else {
+        (0.0, dy.signum())
+    }
+}
+
+/// Shot angles (radians) spread evenly around `aim`
+fn fan_angles(aim: (f32, f32), shots: u32, spread: f32) -> impl Iterator<Item = f32> {
+    let base = aim.1.atan2(aim.0);
+    let spread = spread.to_radians();
+
+    (0..shots).map(move |i| {
+        if shots == 1 {
+            base
+        } else {
+            base - spread / 2.0 + spread * i as f32 / (shots - 1) as f32
+        }
+    })
+}
+
+/// No wall tile on the straight line between two points
+fn clear_line(map: &Grid, x0: f32, y0: f32, x1: f32, y1: f32) -> bool {
+    let dx = x1 - x0;
+    let dy = y1 - y0;
+    let steps = ((dx * dx + dy * dy).sqrt() / 8.0).ceil().max(1.0) as i32;
+
+    (0..=steps).all(|i| {
+        let t = i as f32 / steps as f32;
+        !is_wall(map, x0 + dx * t, y0 + dy * t)
+    })
+}
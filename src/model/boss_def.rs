@@ -0,0 +1,135 @@
+use crate::model::PhasePlan;
+use crate::projectile::ProjectileKind;
+
+/// Sprite names for each boss state.
+pub struct BossSprites {
+    pub idle: &'static str,
+    pub run: &'static str,
+    pub attack: &'static str,
+    pub hurt: &'static str,
+    pub death: &'static str,
+}
+
+/// Everything that makes one boss different. They all share the
+/// state machine in `boss.rs`; a new boss is a new constant here.
+pub struct BossDef {
+    pub name: &'static str,
+    pub lose_message: &'static str,
+    pub sprites: BossSprites,
+    pub tint: u32,            // multiplied over the sprites
+    pub sprite_size: f32,     // drawn square, also the spawn footprint
+    pub collision_size: f32,  // square, centred in the sprite
+    pub max_health: i32,
+    pub melee_damage: i32,
+    pub attack_range: f32,    // lunge reach from the boss's centre
+    pub attack_duration: u32, // frames the lunge animation plays
+    pub attack_impact_frame: u32, // counted down from `attack_duration`
+    pub death_frames: u32,
+    pub stagger_threshold: f32, // damage (blocked or not) that breaks its attack
+    pub bolt: ProjectileKind, // what its fan attacks fire
+    pub phases: PhasePlan,    // behaviour profile (a level can override it)
+}
+
+const NIGHTBORNE_SPRITES: BossSprites = BossSprites {
+    idle: "NightBorne_idle",
+    run: "NightBorne_run",
+    attack: "NightBorne_attack",
+    hurt: "NightBorne_hurt",
+    death: "NightBorne_death",
+};
+
+pub const NIGHTBORNE: BossDef = BossDef {
+    name: "NightBorne",
+    lose_message: "Slain by the NightBorne",
+    sprites: NIGHTBORNE_SPRITES,
+    tint: 0xffffffff,
+    sprite_size: 64.0,
+    collision_size: 28.0,
+    max_health: 120,
+    melee_damage: 1,
+    attack_range: 50.0,
+    attack_duration: 35, // frames to fully play NightBorne_attack.gif
+    attack_impact_frame: 20,
+    death_frames: 90,
+    stagger_threshold: 12.0,
+    bolt: ProjectileKind::NightBolt,
+    phases: PhasePlan::Classic,
+};
+
+/// A giant, slow snowman that hits hard and freezes the ground
+pub const SNOW_KING: BossDef = BossDef {
+    name: "Snow King",
+    lose_message: "Frozen by the Snow King",
+    sprites: BossSprites {
+        idle: "SnowKing_idle",
+        run: "SnowKing_run",
+        attack: "SnowKing_attack",
+        hurt: "SnowKing_hurt",
+        death: "SnowKing_death",
+    },
+    tint: 0xffffffff,
+    sprite_size: 96.0,
+    collision_size: 44.0,
+    max_health: 180,
+    melee_damage: 2,
+    attack_range: 66.0,
+    attack_duration: 40, // frames to fully play SnowKing_attack.gif
+    attack_impact_frame: 22,
+    death_frames: 60,
+    stagger_threshold: 20.0,
+    bolt: ProjectileKind::Icicle,
+    phases: PhasePlan::Blizzard,
+};
+
+/// Quick and relentless – charges down corridors and calls for help
+pub const KRAMPUS: BossDef = BossDef {
+    name: "Krampus",
+    lose_message: "Dragged off by Krampus",
+    sprites: BossSprites {
+        idle: "Krampus_idle",
+        run: "Krampus_run",
+        attack: "Krampus_attack",
+        hurt: "Krampus_hurt",
+        death: "Krampus_death",
+    },
+    tint: 0xffffffff,
+    sprite_size: 64.0,
+    collision_size: 26.0,
+    max_health: 100,
+    melee_damage: 1,
+    attack_range: 50.0,
+    attack_duration: 35, // frames to fully play Krampus_attack.gif
+    attack_impact_frame: 20,
+    death_frames: 90,
+    stagger_threshold: 9.0,
+    bolt: ProjectileKind::Coal,
+    phases: PhasePlan::Hunt,
+};
+
+/// Which boss a level fields (header `boss: <name>`).
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum BossKind {
+    NightBorne,
+    SnowKing,
+    Krampus,
+}
+
+impl BossKind {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "nightborne" => Some(BossKind::NightBorne),
+            "snow_king" => Some(BossKind::SnowKing),
+            "krampus" => Some(BossKind::Krampus),
+            _ => None,
+        }
+    }
+
+    pub fn def(self) -> &'static BossDef {
+        match self {
+            BossKind::NightBorne => &NIGHTBORNE,
+            BossKind::SnowKing => &SNOW_KING,
+            BossKind::Krampus => &KRAMPUS,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+/// One move from a phase's attack set. Everything but the lunge is
+/// telegraphed on the ground first.
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum BossAttack {
+    Lunge,                           // melee swing that slides forward, hits at `BossDef::attack_impact_frame`
+    Fan { shots: u32, spread: f32 }, // bolts spread over `spread` degrees
+    Shockwave { radius: f32 },       // ring rolling out along the ground, stopped by walls
+    Dash { tiles: u32 },             // straight charge down a corridor
+}
+
+impl BossAttack {
+    /// Frames the warning shows before the attack lands
+    pub fn windup_frames(self) -> u32 {
+        match self {
+            BossAttack::Lunge => 0,
+            BossAttack::Fan { .. } => 40,
+            BossAttack::Shockwave { .. } => 50,
+            BossAttack::Dash { .. } => 36,
+        }
+    }
+}
+
+/// How the arena changes when a phase starts.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ArenaChange {
+    Unchanged,
+    Darken(f32), // new ambient darkness, lamps still glow
+    ClearCover,  // trees and presents get flattened
+}
+
+/// Everything that makes one stage of a boss fight different.
+/// A new phase is a new entry in a plan, not new boss code.
+pub struct BossPhase {
+    pub name: &'static str,   // shown on the transition banner
+    pub health_below: f32,    // fraction of max HP that starts it (first phase: 1.0)
+    pub move_speed: f32,
+    pub lunge_speed: f32,
+    pub attack_cooldown: u32, // frames between attacks
+    pub attacks: &'static [BossAttack],
+    pub summons: usize,       // snowmen called in when the phase starts
+    pub arena: ArenaChange,
+}
+
+/// The original fight: gets faster and angrier at half health.
+const CLASSIC: [BossPhase; 2] = [
+    BossPhase {
+        name: "Awakened",
+        health_below: 1.0,
+        move_speed: 2.1,
+        lunge_speed: 2.0,
+        attack_cooldown: 45,
+        attacks: &[BossAttack::Lunge, BossAttack::Fan { shots: 3, spread: 30.0 }],
+        summons: 0,
+        arena: ArenaChange::Unchanged,
+    },
+    BossPhase {
+        name: "Enraged",
+        health_below: 0.5,
+        move_speed: 2.1,
+        lunge_speed: 2.8,
+        attack_cooldown: 30,
+        attacks: &[
+            BossAttack::Lunge,
+            BossAttack::Fan { shots: 5, spread: 50.0 },
+            BossAttack::Shockwave { radius: 140.0 },
+        ],
+        summons: 0,
+        arena: ArenaChange::Unchanged,
+    },
+];
+
+/// Three stages: calls for help and puts the lamps out, then leaves
+/// Santa nowhere to hide.
+const GAUNTLET: [BossPhase; 3] = [
+    BossPhase {
+        name: "Awakened",
+        health_below: 1.0,
+        move_speed: 2.0,
+        lunge_speed: 2.0,
+        attack_cooldown: 48,
+        attacks: &[BossAttack::Lunge, BossAttack::Fan { shots: 3, spread: 30.0 }],
+        summons: 0,
+        arena: ArenaChange::Unchanged,
+    },
+    BossPhase {
+        name: "Call of the Night",
+        health_below: 0.66,
+        move_speed: 2.3,
+        lunge_speed: 2.4,
+        attack_cooldown: 38,
+        attacks: &[
+            BossAttack::Lunge,
+            BossAttack::Fan { shots: 5, spread: 50.0 },
+            BossAttack::Dash { tiles: 8 },
+        ],
+        summons: 2,
+        arena: ArenaChange::Darken(0.9),
+    },
+    BossPhase {
+        name: "Last Stand",
+        health_below: 0.33,
+        move_speed: 2.6,
+        lunge_speed: 3.0,
+        attack_cooldown: 26,
+        attacks: &[
+            BossAttack::Lunge,
+            BossAttack::Fan { shots: 7, spread: 70.0 },
+            BossAttack::Shockwave { radius: 160.0 },
+            BossAttack::Dash { tiles: 10 },
+        ],
+        summons: 0,
+        arena: ArenaChange::ClearCover,
+    },
+];
+
+/// The Snow King: slow, but the ground shakes further every phase.
+const BLIZZARD: [BossPhase; 3] = [
+    BossPhase {
+        name: "Frost Court",
+        health_below: 1.0,
+        move_speed: 1.4,
+        lunge_speed: 1.4,
+        attack_cooldown: 55,
+        attacks: &[BossAttack::Lunge, BossAttack::Shockwave { radius: 150.0 }],
+        summons: 0,
+        arena: ArenaChange::Unchanged,
+    },
+    BossPhase {
+        name: "Whiteout",
+        health_below: 0.6,
+        move_speed: 1.6,
+        lunge_speed: 1.6,
+        attack_cooldown: 45,
+        attacks: &[
+            BossAttack::Lunge,
+            BossAttack::Shockwave { radius: 180.0 },
+            BossAttack::Fan { shots: 6, spread: 80.0 },
+        ],
+        summons: 2,
+        arena: ArenaChange::Darken(0.85),
+    },
+    BossPhase {
+        name: "Avalanche",
+        health_below: 0.25,
+        move_speed: 1.9,
+        lunge_speed: 1.8,
+        attack_cooldown: 35,
+        attacks: &[
+            BossAttack::Lunge,
+            BossAttack::Shockwave { radius: 200.0 },
+            BossAttack::Fan { shots: 8, spread: 110.0 },
+        ],
+        summons: 0,
+        arena: ArenaChange::ClearCover,
+    },
+];
+
+/// Krampus: runs Santa down, then brings friends.
+const HUNT: [BossPhase; 2] = [
+    BossPhase {
+        name: "The Hunt",
+        health_below: 1.0,
+        move_speed: 2.5,
+        lunge_speed: 2.6,
+        attack_cooldown: 40,
+        attacks: &[BossAttack::Lunge, BossAttack::Dash { tiles: 8 }],
+        summons: 0,
+        arena: ArenaChange::Unchanged,
+    },
+    BossPhase {
+        name: "Chains Rattle",
+        health_below: 0.5,
+        move_speed: 2.8,
+        lunge_speed: 3.0,
+        attack_cooldown: 30,
+        attacks: &[
+            BossAttack::Lunge,
+            BossAttack::Dash { tiles: 10 },
+            BossAttack::Fan { shots: 3, spread: 24.0 },
+        ],
+        summons: 3,
+        arena: ArenaChange::Unchanged,
+    },
+];
+
+/// Which phase list a boss fight uses – each boss has its own, a level
+/// can swap it with the header key `boss_phases`.
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum PhasePlan {
+    Classic,
+    Gauntlet,
+    Blizzard,
+    Hunt,
+}
+
+impl PhasePlan {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "classic" => Some(PhasePlan::Classic),
+            "gauntlet" => Some(PhasePlan::Gauntlet),
+            "blizzard" => Some(PhasePlan::Blizzard),
+            "hunt" => Some(PhasePlan::Hunt),
+            _ => None,
+        }
+    }
+
+    /// Ordered by `health_below`, highest first.
+    pub fn phases(self) -> &'static [BossPhase] {
+        match self {
+            PhasePlan::Classic => &CLASSIC,
+            PhasePlan::Gauntlet => &GAUNTLET,
+            PhasePlan::Blizzard => &BLIZZARD,
+            PhasePlan::Hunt => &HUNT,
+        }
+    }
+}
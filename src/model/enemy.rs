@@ -1,14 +1,61 @@
 use turbo::*;
 use crate::{Grid, is_wall};
-const VISION_RADIUS: f32 = 160.0;
-const VISION_ANGLE: f32 = 60.0; // degrees (30° each side)
-use crate::model::{find_path, TileType};
+const CONE_RAYS: usize = 40; // rays per vision cone drawing
+const TAKEDOWN_RANGE: f32 = 30.0; // centre to centre
+const TAKEDOWN_FRAMES: u32 = 36;
+use crate::model::{find_path, find_path_avoiding, tiles_within_steps, EnemyDef, EnemyKind, EnemySpawn, SquadRole};
+use crate::footprint::Footprint;
 use crate::TILE_SIZE;
 
+const WAYPOINT_PAUSE: u32 = 40; // frames spent at each waypoint
+const LOOK_HOLD: u32 = 90; // frames spent staring in each look direction
+const LOOK_TURN_SPEED: f32 = 0.04; // radians per frame
+
+// ─── Suspicion meter (0.0 = calm, 1.0 = spotted) ───
+const SUSPICION_BASE_FILL: f32 = 0.006; // far edge of the cone: ~3s to spot
+const SUSPICION_CLOSE_FILL: f32 = 0.03; // extra when close & centred: ~0.5s
+const SUSPICION_DECAY: f32 = 0.004;
+const SEARCH_AT: f32 = 0.5; // losing Santa above this → go and look
+
+// ─── Searching the last known position ───
+const SEARCH_SPEED: f32 = 1.1;
+const SWEEP_TIME: u32 = 100; // frames of look-around at each search spot
+const SWEEP_ARC: f32 = 1.4; // radians either side of the arrival heading
+const PROBE_MIN_STEPS: usize = 3; // probe spots are this many tiles away…
+const PROBE_MAX_STEPS: usize = 6; // …up to this many
+const CHASE_PROBES: u32 = 3; // extra spots checked after losing a chase
+const GLIMPSE_PROBES: u32 = 1; // …and after losing a mere glimpse
+
+const SCENT_RANGE: f32 = 96.0; // how far a hound smells a footprint
+const HIT_FLASH_FRAMES: u32 = 10; // armour dented but still standing
+const CALL_OUT_FRAMES: u32 = 90; // chasing this long before it gets on the radio
+const HOLD_FRAMES: u32 = 60 * 8; // how long it waits on a squad post for Santa
+const ORDER_FRAMES: u32 = 60 * 6; // how long it keeps heading there without seeing him
+const ALERT_SPEED_BONUS: f32 = 1.4; // patrols and searches hurry while the level is on alarm
+
+/// How a snowman spends its time while nothing is wrong.
+#[turbo::serialize]
+#[derive(PartialEq)]
+pub enum Patrol {
+    /// Random direction every 1–3 seconds (the original behaviour)
+    Wander,
+    /// Walk the tiles in order, then back to the first
+    Loop(Vec<(usize, usize)>),
+    /// Walk the tiles in order, then retrace them backwards
+    PingPong(Vec<(usize, usize)>),
+    /// Stand on `post`, turning to each angle (radians) in turn
+    LookAround { post: (usize, usize), angles: Vec<f32> },
+}
+
 #[turbo::serialize]
 #[derive(PartialEq)]
 pub enum EnemyState {
     Idle,
+    /// Caught a glimpse: stops and stares while the meter fills
+    Suspicious,
+    /// Lost Santa: walks to where he was last seen, looks around,
+    /// checks a few nearby spots, then goes back to patrolling
+    Searching,
     Chasing,
 }
 
@@ -19,21 +66,44 @@ pub struct Enemy {
     pub hitbox: Bounds,
     pub state: EnemyState,
     pub alive: bool,
+    pub kind: EnemyKind,
+    health: u32,       // hits left
+    hit_flash: u32,
+    scent_stamp: u32,  // freshest footprint followed so far
+    on_alert: bool,    // the level alarm is up
+    radio_timer: u32,  // frames into the current chase, until it calls out
+    radioed: bool,     // already called out during this chase
+    pending_call: Option<(f32, f32)>,
+    squad_role: SquadRole,
+    squad_goal: Option<(usize, usize)>, // tile the squad sent it to
+    squad_avoid: Vec<(usize, usize)>,   // tiles to go round on the way there
+    hold_timer: u32,                    // frames spent waiting there
+    order_timer: u32,                   // frames spent getting there
     shoot_cooldown: u32,
 
     pub facing_angle: f32,
 
     patrol_dir: (f32, f32), // NEW
     patrol_timer: u32,      // NEW
-  
-   see_through_walls_timer: u32,
+    pub patrol: Patrol,
+    patrol_index: usize,
+    patrol_step: i32, // +1 forward, -1 backward (ping-pong)
+    patrol_wait: u32,
+
+    search_target: (usize, usize),
+    search_timer: u32, // > 0 while sweeping at a search spot
+    search_origin: f32, // heading the sweep swings around
+    search_probes: u32,
 
     pub alerted_timer: u32,
+    pub suspicion: f32,
+    last_seen: (f32, f32),
     path: Vec<(usize, usize)>,
 path_index: usize,
 repath_timer: u32,
 
-
+    takedown_timer: u32, // > 0 while the takedown animation plays
+    takedown_dir: f32,   // which way it topples (+1 right, -1 left)
 }
 
 
@@ -48,19 +118,148 @@ impl Enemy {
         return;
     }
 
-    self.state = EnemyState::Chasing;
+    self.set_state(EnemyState::Chasing);
     self.alerted_timer = 180; // 🔥 FORCE chase for 3 seconds
+    self.suspicion = 1.0;
 
     let dx = target_x - self.x;
     let dy = target_y - self.y;
 
     self.facing_angle = dy.atan2(dx);
-    self.see_through_walls_timer = 180;
+    self.last_seen = (target_x + 16.0, target_y + 16.0);
 }
 
 
 
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn tile(&self) -> (usize, usize) {
+        world_to_tile(self.x + 16.0, self.y + 16.0)
+    }
+
+    pub fn squad_role(&self) -> SquadRole {
+        self.squad_role
+    }
+
+    pub fn squad_goal(&self) -> Option<(usize, usize)> {
+        self.squad_goal
+    }
+
+    /// New orders from `coordinate_squad` – chase from `goal` instead of
+    /// straight at Santa (`None` → straight at him).
+    pub fn assign_squad(&mut self, role: SquadRole, goal: Option<(usize, usize)>) {
+        if goal.is_none() {
+            self.squad_avoid.clear();
+        }
+        if self.squad_role == role && self.squad_goal == goal {
+            return;
+        }
+
+        self.squad_role = role;
+        self.squad_goal = goal;
+        self.hold_timer = 0;
+        self.order_timer = 0;
+        self.path.clear();
+        self.path_index = 0;
+    }
+
+    /// Tiles its route to the squad goal keeps off where it can, so it
+    /// doesn't end up behind the lead chaser.
+    pub fn avoid_on_the_way(&mut self, tiles: &[(usize, usize)]) {
+        self.squad_avoid.clear();
+        self.squad_avoid.extend_from_slice(tiles);
+    }
+
+    /// Where Santa was last seen, once, when this snowman gets on the
+    /// radio. Killing it before then keeps the others in the dark.
+    pub fn take_call_out(&mut self) -> Option<(f32, f32)> {
+        self.pending_call.take()
+    }
+
+    /// Another snowman radioed in Santa's last known position (centre).
+    pub fn hear_call(&mut self, (x, y): (f32, f32)) {
+        if !self.alive || self.state == EnemyState::Chasing {
+            return;
+        }
+
+        self.last_seen = (x, y);
+        self.suspicion = self.suspicion.max(SEARCH_AT);
+        self.start_search(CHASE_PROBES);
+    }
+
+    /// Heard something at (x, y): go and check it out.
+    pub fn hear(&mut self, x: f32, y: f32) {
+        if !self.alive || self.state == EnemyState::Chasing {
+            return;
+        }
+
+        self.last_seen = (x, y);
+        self.suspicion = self.suspicion.max(SEARCH_AT);
+        self.start_search(GLIMPSE_PROBES);
+    }
+
+    /// Santa is close enough and outside the cone for a silent takedown.
+    /// A snowman that is already chasing can't be surprised.
+    pub fn can_be_taken_down(&self, player_x: f32, player_y: f32) -> bool {
+        if !self.alive || self.state == EnemyState::Chasing {
+            return false;
+        }
+
+        let dx = (player_x + 16.0) - (self.x + 16.0);
+        let dy = (player_y + 16.0) - (self.y + 16.0);
+
+        if (dx * dx + dy * dy).sqrt() > TAKEDOWN_RANGE {
+            return false;
+        }
+
+        let half_cone = self.def().vision_angle.to_radians() / 2.0;
+        wrap_angle(dy.atan2(dx) - self.facing_angle).abs() > half_cone
+    }
+
+    pub fn def(&self) -> &'static EnemyDef {
+        self.kind.def()
+    }
+
+    /// While the level alarm is up patrols hurry and searches don't
+    /// give up until it's over.
+    pub fn set_on_alert(&mut self, on_alert: bool) {
+        self.on_alert = on_alert;
+    }
+
+    fn patrol_speed(&self) -> f32 {
+        if self.on_alert {
+            self.def().patrol_speed * ALERT_SPEED_BONUS
+        } else {
+            self.def().patrol_speed
+        }
+    }
+
+    /// Snowball, bullet or cane hit. Returns true if it went down;
+    /// one that survives turns on whoever hit it.
+    pub fn take_hit(&mut self, from_x: f32, from_y: f32) -> bool {
+        if !self.alive {
+            return false;
+        }
+
+        self.health = self.health.saturating_sub(1);
+        if self.health == 0 {
+            self.alive = false;
+            self.path.clear();
+            return true;
+        }
+
+        self.hit_flash = HIT_FLASH_FRAMES;
+        self.alert(from_x, from_y);
+        false
+    }
+
+    /// Knocked out without a sound – no alert, no noise.
+    pub fn take_down(&mut self, player_x: f32) {
+        self.alive = false;
+        self.path.clear();
+        self.takedown_timer = TAKEDOWN_FRAMES;
+        self.takedown_dir = if self.x >= player_x { 1.0 } else { -1.0 };
+    }
+
+    pub fn new(kind: EnemyKind, x: f32, y: f32) -> Self {
     let angle = random::f32() * std::f32::consts::TAU;
 
     Self {
@@ -69,37 +268,112 @@ impl Enemy {
         hitbox: Bounds::new(x, y, 32, 32),
         state: EnemyState::Idle,
         alive: true,
+        kind,
+        health: kind.def().health,
+        hit_flash: 0,
+        scent_stamp: 0,
+        on_alert: false,
+        radio_timer: 0,
+        radioed: false,
+        pending_call: None,
+        squad_role: SquadRole::Direct,
+        squad_goal: None,
+        squad_avoid: Vec::new(),
+        hold_timer: 0,
+        order_timer: 0,
         shoot_cooldown: 0,
 
         facing_angle: angle,
         patrol_dir: (angle.cos(), angle.sin()),
         patrol_timer: random::between(30, 120),
-        see_through_walls_timer: 0,
+        patrol: Patrol::Wander,
+        patrol_index: 0,
+        patrol_step: 1,
+        patrol_wait: 0,
+        search_target: (0, 0),
+        search_timer: 0,
+        search_origin: 0.0,
+        search_probes: 0,
             alerted_timer: 0,
+            suspicion: 0.0,
+            last_seen: (x + 16.0, y + 16.0),
             path: Vec::new(),
 path_index: 0,
 repath_timer: 0,
+            takedown_timer: 0,
+            takedown_dir: 1.0,
+    }
+}
 
+    /// Snowman placed from a layout marker, with its facing and patrol
+    pub fn from_marker(marker: &EnemySpawn) -> Self {
+        let (tx, ty) = marker.tile;
+        let mut enemy = Self::new(
+            marker.kind,
+            (tx as i32 * TILE_SIZE) as f32,
+            (ty as i32 * TILE_SIZE) as f32,
+        );
 
+        if let Some(angle) = marker.facing {
+            enemy.facing_angle = angle;
+            enemy.patrol_dir = (angle.cos(), angle.sin());
+        }
+        enemy.patrol = marker.patrol.clone();
 
+        enemy
+    }
 
+    /// Changes state, dropping any path planned for the old one.
+    fn set_state(&mut self, state: EnemyState) {
+        if self.state != state {
+            self.path.clear();
+            self.path_index = 0;
+        }
+        // A new chase means a new call
+        if state == EnemyState::Chasing && self.state != EnemyState::Chasing {
+            self.radio_timer = 0;
+            self.radioed = false;
+        }
+        // Squad orders only last for the chase
+        if state != EnemyState::Chasing {
+            self.assign_squad(SquadRole::Direct, None);
+        }
+        self.state = state;
     }
-}
 
 
-fn can_see_player_strict(&self, player_x: f32, player_y: f32, map: &Grid) -> bool {
+/// `visibility` scales how far away Santa can be picked out (sneaking < 1 < sprinting).
+/// A `hidden` Santa is only seen from the next tile over or mid-chase.
+fn can_see_player_strict(
+    &self,
+    player_x: f32,
+    player_y: f32,
+    visibility: f32,
+    hidden: bool,
+    map: &Grid,
+) -> bool {
 
     let ex = self.x + 16.0;
     let ey = self.y + 16.0;
     let px = player_x + 16.0;
     let py = player_y + 16.0;
 
+    // 0. Hiding spot
+    if hidden && self.state != EnemyState::Chasing {
+        let (etx, ety) = world_to_tile(ex, ey);
+        let (ptx, pty) = world_to_tile(px, py);
+
+        if etx.abs_diff(ptx) > 1 || ety.abs_diff(pty) > 1 {
+            return false;
+        }
+    }
+
     let dx = px - ex;
     let dy = py - ey;
     let dist = (dx * dx + dy * dy).sqrt();
 
     // 1. Radius check
-    if dist > VISION_RADIUS {
+    if dist > self.def().vision_radius * visibility {
         return false;
     }
 
@@ -115,7 +389,7 @@ fn can_see_player_strict(&self, player_x: f32, player_y: f32, map: &Grid) -> boo
         angle_diff += std::f32::consts::TAU;
     }
 
-    let half_cone = (VISION_ANGLE.to_radians()) / 2.0;
+    let half_cone = (self.def().vision_angle.to_radians()) / 2.0;
     if angle_diff.abs() > half_cone {
         return false;
     }
@@ -142,32 +416,31 @@ fn can_see_player_strict(&self, player_x: f32, player_y: f32, map: &Grid) -> boo
     true
 }
 
-fn can_see_player_ignore_walls(&self, player_x: f32, player_y: f32) -> bool {
-    let ex = self.x + 16.0;
-    let ey = self.y + 16.0;
-    let px = player_x + 16.0;
-    let py = player_y + 16.0;
+/// How clearly Santa stands out: 0.0 = not visible at all,
+/// 1.0 = point blank in the middle of the cone.
+fn sight_strength(
+    &self,
+    player_x: f32,
+    player_y: f32,
+    visibility: f32,
+    hidden: bool,
+    map: &Grid,
+) -> f32 {
+    if !self.can_see_player_strict(player_x, player_y, visibility, hidden, map) {
+        return 0.0;
+    }
 
-    let dx = px - ex;
-    let dy = py - ey;
+    let dx = (player_x + 16.0) - (self.x + 16.0);
+    let dy = (player_y + 16.0) - (self.y + 16.0);
     let dist = (dx * dx + dy * dy).sqrt();
 
-    if dist > VISION_RADIUS {
-        return false;
-    }
+    let angle_diff = wrap_angle(dy.atan2(dx) - self.facing_angle);
+    let half_cone = (self.def().vision_angle.to_radians()) / 2.0;
 
-    let angle_to_player = dy.atan2(dx);
-    let mut angle_diff = angle_to_player - self.facing_angle;
+    let closeness = 1.0 - dist / (self.def().vision_radius * visibility);
+    let centred = 1.0 - angle_diff.abs() / half_cone;
 
-    while angle_diff > std::f32::consts::PI {
-        angle_diff -= std::f32::consts::TAU;
-    }
-    while angle_diff < -std::f32::consts::PI {
-        angle_diff += std::f32::consts::TAU;
-    }
-
-    let half_cone = (VISION_ANGLE.to_radians()) / 2.0;
-    angle_diff.abs() <= half_cone
+    (closeness * (0.5 + 0.5 * centred)).max(0.01)
 }
 
 
@@ -177,92 +450,169 @@ fn can_see_player_ignore_walls(&self, player_x: f32, player_y: f32) -> bool {
     &mut self,
     player_x: f32,
     player_y: f32,
+    player_visibility: f32,
+    player_hidden: bool,
+    trail: &[Footprint],
     map: &Grid,
 ) -> bool {
+        self.takedown_timer = self.takedown_timer.saturating_sub(1);
+
         if !self.alive {
             return false;
         }
-        self.see_through_walls_timer =
-        self.see_through_walls_timer.saturating_sub(1);
         self.shoot_cooldown = self.shoot_cooldown.saturating_sub(1);
+        self.hit_flash = self.hit_flash.saturating_sub(1);
 
             // 🚨 ALERT OVERRIDE (force chase even without vision)
     if self.alerted_timer > 0 {
         self.alerted_timer -= 1;
-        self.state = EnemyState::Chasing;
+        self.set_state(EnemyState::Chasing);
     }
 
 
+        // 👁 Suspicion meter (everything short of a full chase)
+        let mut glimpsed = false;
+
+        if self.state != EnemyState::Chasing {
+            let sight = self.sight_strength(player_x, player_y, player_visibility, player_hidden, map);
+
+            if sight > 0.0 {
+                glimpsed = true;
+                self.suspicion += SUSPICION_BASE_FILL + SUSPICION_CLOSE_FILL * sight;
+                self.last_seen = (player_x + 16.0, player_y + 16.0);
+
+                if self.state != EnemyState::Suspicious {
+                    self.set_state(EnemyState::Suspicious);
+                }
+            } else if self.state == EnemyState::Searching {
+                self.suspicion -= SUSPICION_DECAY / 2.0; // keeps looking longer
+            } else {
+                self.suspicion -= SUSPICION_DECAY;
+            }
+
+            self.suspicion = self.suspicion.clamp(0.0, 1.0);
+
+            if self.suspicion >= 1.0 {
+                self.set_state(EnemyState::Chasing);
+                return false;
+            }
+        }
+
+        // 🐾 Nose to the ground
+        if self.def().tracks_scent
+            && matches!(self.state, EnemyState::Idle | EnemyState::Searching)
+        {
+            self.follow_scent(trail);
+        }
+
         match self.state {
             EnemyState::Idle => {
-    if self.can_see_player_strict(player_x, player_y, map) {
-        self.state = EnemyState::Chasing;
-        
-        return false;
+    // Lookouts keep turning on the spot
+    if self.def().scan_speed > 0.0 {
+        self.facing_angle = wrap_angle(self.facing_angle + self.def().scan_speed);
+    } else {
+        // Patrol
+        match self.patrol {
+            Patrol::Wander if self.def().stationary => {}
+            Patrol::Wander => self.wander(map),
+            Patrol::Loop(_) | Patrol::PingPong(_) => self.walk_route(map),
+            Patrol::LookAround { .. } => self.look_around(map),
+        }
     }
+}
 
-    // Patrol
-    let speed = 0.6;
-    let (dx, dy) = self.patrol_dir;
-    self.facing_angle = dy.atan2(dx);
+            EnemyState::Suspicious => {
+                // Stop and stare at the glimpse
+                self.turn_towards(self.last_seen, LOOK_TURN_SPEED * 2.0);
 
-    let try_x = self.x + dx * speed;
-    if !is_wall(map, try_x + 16.0, self.y + 16.0) {
-        self.x = try_x;
-    } else {
-        self.patrol_timer = 0;
-    }
+                if !glimpsed {
+                    if self.suspicion >= SEARCH_AT {
+                        self.start_search(GLIMPSE_PROBES);
+                    } else if self.suspicion <= 0.0 {
+                        self.set_state(EnemyState::Idle);
+                    }
+                }
+            }
 
-    let try_y = self.y + dy * speed;
-    if !is_wall(map, self.x + 16.0, try_y + 16.0) {
-        self.y = try_y;
-    } else {
-        self.patrol_timer = 0;
+            EnemyState::Searching => self.search(map),
+
+            EnemyState::Chasing => {
+    let sees_player = self.can_see_player_strict(
+        player_x,
+        player_y,
+        player_visibility,
+        player_hidden,
+        map,
+    );
+
+    if sees_player || self.alerted_timer > 0 {
+        self.last_seen = (player_x + 16.0, player_y + 16.0);
     }
 
-    self.patrol_timer = self.patrol_timer.saturating_sub(1);
-    if self.patrol_timer == 0 {
-        let angle = random::f32() * std::f32::consts::TAU;
-        self.patrol_dir = (angle.cos(), angle.sin());
-        self.facing_angle = angle;
-        self.patrol_timer = random::between(60, 180);
-    }}
+    // 📻 Long enough on his tail → tell everyone in range
+    if !self.radioed && self.def().calls_out {
+        self.radio_timer += 1;
+        if self.radio_timer >= CALL_OUT_FRAMES {
+            self.radioed = true;
+            self.pending_call = Some(self.last_seen);
+        }
+    }
 
-            EnemyState::Chasing => {
-                // Santa just disappeared behind a wall → start grace period
-if self.see_through_walls_timer == 0
-    && !self.can_see_player_strict(player_x, player_y, map)
-{
-    self.see_through_walls_timer = 180; // 3 seconds @ 60 FPS
-}
+    // 🧭 Waiting where the squad posted it (a chokepoint or the exit)
+    let enemy_tile = self.tile();
+    let on_post = self.squad_role.holds() && self.squad_goal == Some(enemy_tile);
+    if on_post {
+        self.hold_timer += 1;
+        if self.hold_timer >= HOLD_FRAMES {
+            self.assign_squad(SquadRole::Direct, None);
+        }
+    }
 
-    let sees_player = if self.see_through_walls_timer > 0 {
-        self.can_see_player_ignore_walls(player_x, player_y)
-    } else {
-        self.can_see_player_strict(player_x, player_y, map)
-    };
+    // Still on its way there – routed round a wall, so out of sight is expected
+    if self.squad_goal.is_some_and(|goal| goal != enemy_tile) {
+        self.order_timer += 1;
+        if self.order_timer >= ORDER_FRAMES {
+            self.assign_squad(SquadRole::Direct, None);
+        }
+    }
+    let en_route = self.squad_goal.is_some_and(|goal| goal != enemy_tile);
 
-    if !sees_player && self.alerted_timer == 0 {
-    self.state = EnemyState::Idle;
-    return false;
-}
+    // Lost him → go to where he was last seen instead of tracking through walls
+    // (unless the squad sent it somewhere or told it to wait for him)
+    if !sees_player && self.alerted_timer == 0 && !on_post && !en_route {
+        self.start_search(CHASE_PROBES);
+        return false;
+    }
 
 
     
 
+    // Snipers and lookouts hold their post and just track him
+    if self.def().stationary || on_post {
+        self.turn_towards(self.last_seen, 0.1);
+    } else {
+
     self.repath_timer = self.repath_timer.saturating_sub(1);
 
 // Compute tiles
-let enemy_tile = world_to_tile(self.x + 16.0, self.y + 16.0);
 let player_tile = world_to_tile(player_x + 16.0, player_y + 16.0);
 
-// Repath occasionally OR when alerted
-if self.repath_timer == 0 || self.path.is_empty() {
-    if let Some(p) = find_path(map, enemy_tile, player_tile) {
-        self.path = p;
-        self.path_index = 0;
-        self.repath_timer = 30; // recalc every 0.5s
+// Squad orders send it somewhere else first
+let orders = self.squad_goal.filter(|&goal| goal != enemy_tile);
+let goal_tile = orders.unwrap_or(player_tile);
+
+// Repath occasionally, when alerted OR when the orders change
+if (self.repath_timer == 0
+    || self.path.is_empty()
+    || orders.is_some_and(|goal| self.path.last() != Some(&goal)))
+    && let Some(p) = match orders {
+        Some(goal) => find_path_avoiding(map, enemy_tile, goal, &self.squad_avoid),
+        None => find_path(map, enemy_tile, goal_tile),
     }
+{
+    self.path = p;
+    self.path_index = 0;
+    self.repath_timer = 30; // recalc every 0.5s
 }
 
 // Follow path
@@ -277,7 +627,7 @@ if self.path_index < self.path.len() {
     if dist < 4.0 {
         self.path_index += 1;
     } else {
-        let speed = 1.6;
+        let speed = self.def().chase_speed;
         let nx = dx / dist;
         let ny = dy / dist;
 
@@ -294,9 +644,10 @@ if self.path_index < self.path.len() {
         self.facing_angle = ny.atan2(nx);
     }
 }
+    }
 
 
-    // 🎯 SHOOT IF SANTA IN FRONT
+    // 🎯 ATTACK IF SANTA IN FRONT
     let ex = self.x + 16.0;
     let ey = self.y + 16.0;
     let px = player_x + 16.0;
@@ -317,10 +668,10 @@ if self.path_index < self.path.len() {
         diff += std::f32::consts::TAU;
     }
 
-    let in_front = diff.abs() <= (VISION_ANGLE.to_radians() / 2.0);
+    let in_front = diff.abs() <= (self.def().vision_angle.to_radians() / 2.0);
 
-    if in_front && dist < 120.0 && self.shoot_cooldown == 0 {
-        self.shoot_cooldown = 45;
+    if in_front && dist < self.def().attack_range && self.shoot_cooldown == 0 {
+        self.shoot_cooldown = self.def().attack_cooldown;
 
         // 🔫 muzzle flash
         circ!(
@@ -330,7 +681,7 @@ if self.path_index < self.path.len() {
             color = 0xffeeaa88
         );
 
-        return true; // tells GameState to spawn bullet
+        return true; // tells GameState to attack (see `def().attack`)
     }
 
 }
@@ -343,48 +694,459 @@ if self.path_index < self.path.len() {
         false
     }
 
-    pub fn draw(&self) {
+    fn wander(&mut self, map: &Grid) {
+        let (dx, dy) = self.patrol_dir;
+        self.facing_angle = dy.atan2(dx);
+
+        let try_x = self.x + dx * self.patrol_speed();
+        if !is_wall(map, try_x + 16.0, self.y + 16.0) {
+            self.x = try_x;
+        } else {
+            self.patrol_timer = 0;
+        }
+
+        let try_y = self.y + dy * self.patrol_speed();
+        if !is_wall(map, self.x + 16.0, try_y + 16.0) {
+            self.y = try_y;
+        } else {
+            self.patrol_timer = 0;
+        }
+
+        self.patrol_timer = self.patrol_timer.saturating_sub(1);
+        if self.patrol_timer == 0 {
+            let angle = random::f32() * std::f32::consts::TAU;
+            self.patrol_dir = (angle.cos(), angle.sin());
+            self.facing_angle = angle;
+            self.patrol_timer = random::between(60, 180);
+        }
+    }
+
+    /// Loop / ping-pong: walk to the current waypoint, pause, pick the next.
+    fn walk_route(&mut self, map: &Grid) {
+        let (len, target, ping_pong) = match &self.patrol {
+            Patrol::Loop(route) if !route.is_empty() => {
+                (route.len(), route[self.patrol_index % route.len()], false)
+            }
+            Patrol::PingPong(route) if !route.is_empty() => {
+                (route.len(), route[self.patrol_index % route.len()], true)
+            }
+            _ => return,
+        };
+
+        if self.patrol_wait > 0 {
+            self.patrol_wait -= 1;
+            return;
+        }
+
+        if !self.walk_to_tile(map, target, self.patrol_speed()) {
+            return;
+        }
+
+        self.patrol_wait = WAYPOINT_PAUSE;
+
+        if ping_pong && len > 1 {
+            let next = self.patrol_index as i32 + self.patrol_step;
+            if next < 0 || next >= len as i32 {
+                self.patrol_step = -self.patrol_step;
+            }
+            self.patrol_index = (self.patrol_index as i32 + self.patrol_step) as usize;
+        } else {
+            self.patrol_index = (self.patrol_index + 1) % len;
+        }
+    }
+
+    /// Stand-and-look: get back to the post, then sweep through the angles.
+    fn look_around(&mut self, map: &Grid) {
+        let (post, target) = match &self.patrol {
+            Patrol::LookAround { post, angles } if !angles.is_empty() => {
+                (*post, angles[self.patrol_index % angles.len()])
+            }
+            _ => return,
+        };
+
+        if !self.walk_to_tile(map, post, self.patrol_speed()) {
+            return;
+        }
+
+        if self.patrol_wait > 0 {
+            self.patrol_wait -= 1;
+            if self.patrol_wait == 0 {
+                self.patrol_index += 1;
+            }
+            return;
+        }
+
+        let diff = wrap_angle(target - self.facing_angle);
+        if diff.abs() > LOOK_TURN_SPEED {
+            self.facing_angle = wrap_angle(self.facing_angle + LOOK_TURN_SPEED * diff.signum());
+        } else {
+            self.facing_angle = target;
+            self.patrol_wait = LOOK_HOLD;
+        }
+    }
+
+    fn start_search(&mut self, probes: u32) {
+        self.set_state(EnemyState::Searching);
+        self.search_timer = 0;
+
+        // Posted enemies look around from where they stand
+        if self.def().stationary {
+            self.search_target = world_to_tile(self.x + 16.0, self.y + 16.0);
+            self.search_probes = 0;
+            return;
+        }
+
+        self.search_target = world_to_tile(self.last_seen.0, self.last_seen.1);
+        self.search_probes = probes;
+    }
+
+    /// Hounds: head for the freshest footprint within smelling range
+    /// that's newer than the one they're already on.
+    fn follow_scent(&mut self, trail: &[Footprint]) {
+        let ex = self.x + 16.0;
+        let ey = self.y + 16.0;
+
+        let fresher = trail
+            .iter()
+            .filter(|print| print.stamp > self.scent_stamp)
+            .filter(|print| ((print.x - ex).powi(2) + (print.y - ey).powi(2)).sqrt() <= SCENT_RANGE)
+            .max_by_key(|print| print.stamp);
+
+        if let Some(print) = fresher {
+            self.scent_stamp = print.stamp;
+            self.last_seen = (print.x, print.y);
+            self.start_search(GLIMPSE_PROBES);
+        }
+    }
+
+    /// Walk to the search spot, sweep the view around, then either pick
+    /// another nearby spot to probe or give up and resume the patrol.
+    fn search(&mut self, map: &Grid) {
+        if self.search_timer > 0 {
+            self.search_timer -= 1;
+
+            let t = (SWEEP_TIME - self.search_timer) as f32 / SWEEP_TIME as f32;
+            let swing = (t * std::f32::consts::TAU).sin() * SWEEP_ARC;
+            self.facing_angle = wrap_angle(self.search_origin + swing);
+
+            if self.search_timer == 0 {
+                // On alarm, mobile ones keep checking new spots
+                let keep_looking = self.on_alert && !self.def().stationary;
+                if self.search_probes == 0 && !keep_looking {
+                    self.set_state(EnemyState::Idle);
+                    return;
+                }
+
+                self.search_probes = self.search_probes.saturating_sub(1);
+                self.search_target = self.pick_probe_spot(map);
+            }
+            return;
+        }
+
+        let speed = if self.on_alert { SEARCH_SPEED * ALERT_SPEED_BONUS } else { SEARCH_SPEED };
+        if self.walk_to_tile(map, self.search_target, speed) {
+            self.search_origin = self.facing_angle;
+            self.search_timer = SWEEP_TIME;
+        }
+    }
+
+    /// A random floor tile a few steps away – down a side corridor,
+    /// round the next corner – to check next.
+    fn pick_probe_spot(&self, map: &Grid) -> (usize, usize) {
+        let here = world_to_tile(self.x + 16.0, self.y + 16.0);
+
+        let mut spots: Vec<(usize, usize)> = tiles_within_steps(map, here, PROBE_MAX_STEPS)
+            .into_iter()
+            .filter(|&(_, steps)| steps >= PROBE_MIN_STEPS)
+            .map(|(tile, _)| tile)
+            .collect();
+
+        random::shuffle(&mut spots);
+        spots.first().copied().unwrap_or(here)
+    }
+
+    fn turn_towards(&mut self, (tx, ty): (f32, f32), speed: f32) {
+        let target = (ty - (self.y + 16.0)).atan2(tx - (self.x + 16.0));
+        let diff = wrap_angle(target - self.facing_angle);
+        self.facing_angle = wrap_angle(self.facing_angle + diff.clamp(-speed, speed));
+    }
+
+    /// Steps along a `find_path` route to `goal`. Returns true once the
+    /// snowman is standing on the goal tile (or it can't be reached).
+    fn walk_to_tile(&mut self, map: &Grid, goal: (usize, usize), speed: f32) -> bool {
+        let (gx, gy) = tile_center(goal.0, goal.1);
+        let to_goal_x = gx - (self.x + 16.0);
+        let to_goal_y = gy - (self.y + 16.0);
+
+        if (to_goal_x * to_goal_x + to_goal_y * to_goal_y).sqrt() < 4.0 {
+            self.path.clear();
+            return true;
+        }
+
+        // Plan (or re-plan) when the goal changed
+        if self.path.last() != Some(&goal) {
+            let here = world_to_tile(self.x + 16.0, self.y + 16.0);
+            match find_path(map, here, goal) {
+                Some(p) => {
+                    self.path = p;
+                    self.path_index = 0;
+                }
+                None => return true,
+            }
+        }
+
+        if self.path_index >= self.path.len() {
+            self.path_index = self.path.len() - 1;
+        }
+
+        let (tx, ty) = self.path[self.path_index];
+        let (cx, cy) = tile_center(tx, ty);
+
+        let dx = cx - (self.x + 16.0);
+        let dy = cy - (self.y + 16.0);
+        let dist = (dx * dx + dy * dy).sqrt();
+
+        if dist < speed.max(1.0) {
+            self.x = cx - 16.0;
+            self.y = cy - 16.0;
+            self.path_index += 1;
+            return false;
+        }
+
+        let nx = dx / dist;
+        let ny = dy / dist;
+
+        let try_x = self.x + nx * speed;
+        if !is_wall(map, try_x + 16.0, self.y + 16.0) {
+            self.x = try_x;
+        }
+
+        let try_y = self.y + ny * speed;
+        if !is_wall(map, self.x + 16.0, try_y + 16.0) {
+            self.y = try_y;
+        }
+
+        self.facing_angle = ny.atan2(nx);
+        false
+    }
+
+    /// `visibility` is Santa's, as passed to `update` – it sizes the cone's fill.
+    pub fn draw(&self, map: &Grid, show_cone: bool, visibility: f32) {
         if !self.alive {
+            if self.takedown_timer > 0 {
+                self.draw_takedown();
+            }
             return;
         }
 
+        // 👁 Cone underneath the snowman
+        if show_cone {
+            self.draw_vision_cone(map, visibility);
+        }
+
+        // Smaller / bigger kinds stand on the same tile-sized spot
+        let def = self.def();
+        let inset = (32 - def.draw_size as i32) / 2;
+        let tint = if self.hit_flash > 0 { 0xff8080ff } else { def.tint };
+
         sprite!(
-            "snowman",
-            x = self.x as i32,
-            y = self.y as i32,
-            w = 32,
-            h = 32,
+            def.sprite,
+            x = self.x as i32 + inset,
+            y = self.y as i32 + inset * 2,
+            w = def.draw_size,
+            h = def.draw_size,
+            flip_x = self.facing_angle.cos() < 0.0, // sprites face right
+            color = tint,
             cover = true
         );
-        // DEBUG: vision cone
-let ex = self.x + 16.0;
-let ey = self.y + 16.0;
-
-let left = self.facing_angle - (VISION_ANGLE.to_radians() / 2.0);
-let right = self.facing_angle + (VISION_ANGLE.to_radians() / 2.0);
-
-path!(
-    start = (ex as i32, ey as i32),
-    end = (
-        (ex + left.cos() * VISION_RADIUS) as i32,
-        (ey + left.sin() * VISION_RADIUS) as i32
-    ),
-    color = 0xff000088
-);
-
-path!(
-    start = (ex as i32, ey as i32),
-    end = (
-        (ex + right.cos() * VISION_RADIUS) as i32,
-        (ey + right.sin() * VISION_RADIUS) as i32
-    ),
-    color = 0xff000088
-);
 
+        // 🛡 One pip per hit it can still take
+        if def.health > 1 {
+            for i in 0..self.health as i32 {
+                rect!(x = self.x as i32 + 2 + i * 6, y = self.y as i32 + 34, w = 4, h = 3, color = 0xcfd8e8ff);
+            }
+        }
+
+        self.draw_suspicion();
+    }
+
+    /// Topples over away from Santa in a puff of snow, then fades out.
+    fn draw_takedown(&self) {
+        let t = 1.0 - self.takedown_timer as f32 / TAKEDOWN_FRAMES as f32;
+        let tip = (t / 0.5).min(1.0); // first half: fall over
+        let fade = ((t - 0.5) / 0.5).clamp(0.0, 1.0); // second half: melt away
+
+        // Same spot and size as it stood, tipping over from its feet
+        let def = self.def();
+        let inset = (32 - def.draw_size as i32) / 2;
+
+        sprite!(
+            def.sprite,
+            x = self.x as i32 + inset,
+            y = self.y as i32 + inset * 2,
+            w = def.draw_size,
+            h = def.draw_size,
+            color = def.tint,
+            origin = (def.draw_size as i32 / 2, def.draw_size as i32),
+            rotation = (tip * tip * 90.0 * self.takedown_dir) as i32,
+            opacity = 1.0 - fade,
+            cover = true
+        );
+
+        // ❄️ Puff where it lands
+        if tip >= 1.0 {
+            let r = (6.0 + fade * 14.0) as i32;
+            let alpha = ((1.0 - fade) * 200.0) as u32;
+            let gx = (self.x + 16.0 + self.takedown_dir * 22.0) as i32;
+            let gy = (self.y + 28.0) as i32;
+
+            for ox in [-8, 0, 8] {
+                circ!(
+                    x = gx + ox - r / 2,
+                    y = gy - r / 2,
+                    d = r as u32,
+                    color = 0xffffff00 | alpha
+                );
+            }
+        }
+    }
+
+    /// What this snowman can see, tinted by how alert it is.
+    fn draw_vision_cone(&self, map: &Grid, visibility: f32) {
+        let ex = self.x + 16.0;
+        let ey = self.y + 16.0;
+        let half_cone = self.def().vision_angle.to_radians() / 2.0;
+
+        let tint = match self.state {
+            EnemyState::Idle => 0xffffff00,
+            EnemyState::Suspicious | EnemyState::Searching => 0xffb03000,
+            EnemyState::Chasing => 0xff303000,
+        };
+
+        draw_cone(map, (ex, ey), self.facing_angle, half_cone, self.def().vision_radius, visibility, tint);
+    }
+
+    /// "?" + meter while suspicious, red "!" once chasing
+    fn draw_suspicion(&self) {
+        let x = self.x as i32;
+        let y = self.y as i32;
+
+        if self.state == EnemyState::Chasing {
+            text!("!", x = x + 12, y = y - 18, scale = 2.0, color = 0xff3b3bff);
+
+            // 📻 Time left to silence it before it calls out
+            if self.radio_timer > 0 && !self.radioed {
+                let filled = 20 * self.radio_timer / CALL_OUT_FRAMES;
+                rect!(x = x + 6, y = y - 6, w = 20, h = 4, color = 0x000000aa);
+                rect!(x = x + 6, y = y - 6, w = filled, h = 4, color = 0x66ccffff);
+            }
+            return;
+        }
+
+        if self.suspicion <= 0.0 {
+            return;
+        }
+
+        let filled = (20.0 * self.suspicion) as u32;
+        let color = if self.suspicion >= SEARCH_AT { 0xff9900ff } else { 0xffee55ff };
+
+        text!("?", x = x + 12, y = y - 20, scale = 1.5, color = color);
+
+        rect!(x = x + 6, y = y - 6, w = 20, h = 4, color = 0x000000aa);
+        rect!(x = x + 6, y = y - 6, w = filled, h = 4, color = color);
+    }
+}
+
+
+
+/// A vision cone, each ray cut short by the first wall: outlined out to
+/// `radius`, filled out to `radius * visibility` – how close Santa has to
+/// get to be picked out right now. `tint` is 0xRRGGBB00 – the alpha is
+/// filled in here.
+pub(crate) fn draw_cone(
+    map: &Grid,
+    (ex, ey): (f32, f32),
+    facing: f32,
+    half_cone: f32,
+    radius: f32,
+    visibility: f32,
+    tint: u32,
+) {
+    let edge = |reach: f32| -> Vec<(f32, f32)> {
+        (0..=CONE_RAYS)
+            .map(|i| {
+                let angle = facing - half_cone + 2.0 * half_cone * i as f32 / CONE_RAYS as f32;
+                cast_ray(map, ex, ey, angle, reach)
+            })
+            .collect()
+    };
+
+    // Fill: one polygon from the snowman round the clipped edge
+    let mut fill = vec![(ex, ey)];
+    fill.extend(edge(radius * visibility));
+    fill_polygon(&fill, tint | 0x30);
+
+    // Outline: both sides plus the wall-clipped far edge
+    let mut outline = vec![(ex, ey)];
+    outline.extend(edge(radius));
+    outline.push((ex, ey));
+
+    for pair in outline.windows(2) {
+        path!(
+            start = (pair[0].0 as i32, pair[0].1 as i32),
+            end = (pair[1].0 as i32, pair[1].1 as i32),
+            color = tint | 0x88
+        );
     }
 }
 
+/// Scanline fill: one rect per span per row, so nothing overlaps and
+/// the alpha stays even.
+fn fill_polygon(points: &[(f32, f32)], color: u32) {
+    const ROW: i32 = 2; // px per scanline
+
+    let top = points.iter().map(|p| p.1).fold(f32::MAX, f32::min).floor() as i32;
+    let bottom = points.iter().map(|p| p.1).fold(f32::MIN, f32::max).ceil() as i32;
 
+    let mut crossings = Vec::new();
+    for y in (top..bottom).step_by(ROW as usize) {
+        let sy = y as f32 + ROW as f32 / 2.0;
+
+        crossings.clear();
+        for (i, &(ax, ay)) in points.iter().enumerate() {
+            let (bx, by) = points[(i + 1) % points.len()];
+            if (ay <= sy) != (by <= sy) {
+                crossings.push(ax + (sy - ay) / (by - ay) * (bx - ax));
+            }
+        }
+        crossings.sort_by(f32::total_cmp);
+
+        for span in crossings.chunks_exact(2) {
+            let x0 = span[0].round() as i32;
+            let x1 = span[1].round() as i32;
+            if x1 > x0 {
+                rect!(x = x0, y = y, w = (x1 - x0) as u32, h = ROW as u32, color = color);
+            }
+        }
+    }
+}
+
+/// Walks a ray out from (x, y) and returns where it first hits a wall,
+/// or its end point at `max_dist`.
+pub(crate) fn cast_ray(map: &Grid, x: f32, y: f32, angle: f32, max_dist: f32) -> (f32, f32) {
+    let (dy, dx) = angle.sin_cos();
+    let mut dist = 0.0;
+
+    while dist < max_dist {
+        let next = (dist + 4.0).min(max_dist);
+        if is_wall(map, x + dx * next, y + dy * next) {
+            return (x + dx * dist, y + dy * dist);
+        }
+        dist = next;
+    }
+
+    (x + dx * max_dist, y + dy * max_dist)
+}
 
 fn world_to_tile(x: f32, y: f32) -> (usize, usize) {
     (
@@ -393,6 +1155,17 @@ fn world_to_tile(x: f32, y: f32) -> (usize, usize) {
     )
 }
 
+/// Normalizes an angle to [-PI, PI]
+pub(crate) fn wrap_angle(mut angle: f32) -> f32 {
+    while angle > std::f32::consts::PI {
+        angle -= std::f32::consts::TAU;
+    }
+    while angle < -std::f32::consts::PI {
+        angle += std::f32::consts::TAU;
+    }
+    angle
+}
+
 fn tile_center(tx: usize, ty: usize) -> (f32, f32) {
     (
         tx as f32 * TILE_SIZE as f32 + TILE_SIZE as f32 / 2.0,
@@ -0,0 +1,144 @@
+use crate::projectile::ProjectileKind;
+
+/// What an enemy does once Santa is in reach.
+#[derive(Clone, Copy, PartialEq)]
+pub enum EnemyAttack {
+    Shoot(ProjectileKind),
+    Bite, // close-range lunge, no projectile
+}
+
+/// Everything that makes one kind of enemy different. They all share
+/// the patrol / suspicion / search / chase logic in `enemy.rs`.
+pub struct EnemyDef {
+    pub sprite: &'static str,
+    pub tint: u32,
+    pub draw_size: u32,      // the hitbox stays one tile
+    pub health: u32,         // hits to put it down
+    pub vision_radius: f32,
+    pub vision_angle: f32,   // degrees, whole cone
+    pub scan_speed: f32,     // radians/frame it turns on its own while calm (0 = never)
+    pub patrol_speed: f32,
+    pub chase_speed: f32,
+    pub stationary: bool,    // never leaves its tile, even mid-chase
+    pub tracks_scent: bool,  // follows Santa's footprints
+    pub calls_out: bool,     // radios the others once it's been chasing a while
+    pub attack: EnemyAttack,
+    pub attack_range: f32,
+    pub attack_cooldown: u32,
+}
+
+pub const SNOWMAN: EnemyDef = EnemyDef {
+    sprite: "snowman",
+    tint: 0xffffffff,
+    draw_size: 32,
+    health: 1,
+    vision_radius: 160.0,
+    vision_angle: 60.0,
+    scan_speed: 0.0,
+    patrol_speed: 0.6,
+    chase_speed: 1.6,
+    stationary: false,
+    tracks_scent: false,
+    calls_out: true,
+    attack: EnemyAttack::Shoot(ProjectileKind::SnowBullet),
+    attack_range: 120.0,
+    attack_cooldown: 45,
+};
+
+/// Stays put and sees down a whole corridor – through a narrow cone
+pub const SNIPER: EnemyDef = EnemyDef {
+    sprite: "snowman_sniper",
+    tint: 0xffffffff,
+    draw_size: 32,
+    health: 1,
+    vision_radius: 300.0,
+    vision_angle: 24.0,
+    scan_speed: 0.0,
+    patrol_speed: 0.0,
+    chase_speed: 0.0,
+    stationary: true,
+    tracks_scent: false,
+    calls_out: true,
+    attack: EnemyAttack::Shoot(ProjectileKind::SniperShot),
+    attack_range: 300.0,
+    attack_cooldown: 100,
+};
+
+/// Fast, short-sighted, follows footprints and bites
+pub const REINDEER_HOUND: EnemyDef = EnemyDef {
+    sprite: "reindeer_hound",
+    tint: 0xffffffff,
+    draw_size: 26,
+    health: 1,
+    vision_radius: 120.0,
+    vision_angle: 90.0,
+    scan_speed: 0.0,
+    patrol_speed: 1.0,
+    chase_speed: 2.6,
+    stationary: false,
+    tracks_scent: true,
+    calls_out: false,
+    attack: EnemyAttack::Bite,
+    attack_range: 26.0,
+    attack_cooldown: 50,
+};
+
+/// Keeps its post, turning its gaze all the way round
+pub const ELF_LOOKOUT: EnemyDef = EnemyDef {
+    sprite: "elf_lookout",
+    tint: 0xffffffff,
+    draw_size: 24,
+    health: 1,
+    vision_radius: 180.0,
+    vision_angle: 45.0,
+    scan_speed: 0.025,
+    patrol_speed: 0.0,
+    chase_speed: 0.0,
+    stationary: true,
+    tracks_scent: false,
+    calls_out: true,
+    attack: EnemyAttack::Shoot(ProjectileKind::SnowBullet),
+    attack_range: 140.0,
+    attack_cooldown: 60,
+};
+
+/// Slow, but the first hit only dents the armour
+pub const ARMOURED: EnemyDef = EnemyDef {
+    sprite: "snowman_armoured",
+    tint: 0xffffffff,
+    draw_size: 34,
+    health: 2,
+    vision_radius: 150.0,
+    vision_angle: 60.0,
+    scan_speed: 0.0,
+    patrol_speed: 0.45,
+    chase_speed: 1.2,
+    stationary: false,
+    tracks_scent: false,
+    calls_out: true,
+    attack: EnemyAttack::Shoot(ProjectileKind::SnowBullet),
+    attack_range: 120.0,
+    attack_cooldown: 55,
+};
+
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum EnemyKind {
+    Snowman,
+    Sniper,
+    ReindeerHound,
+    ElfLookout,
+    Armoured,
+}
+
+impl EnemyKind {
+    pub fn def(self) -> &'static EnemyDef {
+        match self {
+            EnemyKind::Snowman => &SNOWMAN,
+            EnemyKind::Sniper => &SNIPER,
+            EnemyKind::ReindeerHound => &REINDEER_HOUND,
+            EnemyKind::ElfLookout => &ELF_LOOKOUT,
+            EnemyKind::Armoured => &ARMOURED,
+        }
+    }
+}
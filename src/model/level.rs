@@ -1,96 +1,505 @@
 use super::*;
+use crate::cover::CoverKind;
+use crate::gift::GiftType;
+use crate::sentry::TrapKind;
+use std::fmt;
+
+/// Ambient darkness when the header doesn't say (neutral vision range).
+const DEFAULT_DARKNESS: f32 = 0.5;
+
+// `LEVEL_FILES`, generated by build.rs from levels/*.txt
+include!(concat!(env!("OUT_DIR"), "/levels.rs"));
+
+#[turbo::serialize]
+pub struct EnemySpawn {
+    pub kind: EnemyKind,
+    pub tile: (usize, usize),
+    /// `None` → random facing (plain `E` marker)
+    pub facing: Option<f32>,
+    pub patrol: Patrol,
+}
+
+#[turbo::serialize]
+pub struct CameraSpawn {
+    pub tile: (usize, usize),
+    /// (start, arc) in radians – `None` → turns all the way round
+    pub sweep: Option<(f32, f32)>,
+}
 
-#[derive(Clone, Copy)]
+/// Where things start, as authored in the layout.
+/// Empty lists / `None` mean "place at random".
+#[turbo::serialize]
 pub struct SpawnInfo {
     pub player_spawn: (usize, usize),
+    pub enemies: Vec<EnemySpawn>,
+    pub boss: Option<(usize, usize)>,
+    pub gifts: Vec<((usize, usize), GiftType)>,
+    pub exit: Option<(usize, usize)>,
+    /// Hiding spots placed by hand (dead-end trees are added on top)
+    pub cover: Vec<((usize, usize), CoverKind)>,
+    pub cameras: Vec<CameraSpawn>,
+    pub traps: Vec<((usize, usize), TrapKind)>,
 }
 
-pub fn load_level_1() -> (Grid, SpawnInfo) {
-    let layout = vec![
-        "########################",
-        "#..........#...........#",
-        "#..######..#..#####....#",
-        "#..#....#..#..#...#....#",
-        "#..#....#......#...#..#",
-        "#..##.###..######...#..#",
-        "#..........#...........#",
-        "######..########..######",
-        "#..........#...........#",
-        "#..#.####..#..#####....#",
-        "#..#....#..#..#...#....#",
-        "#..#....#......#...#..#",
-        "#..######..######...#..#",
-        "#..........#...........#",
-        "########################",
-    ];
+/// Everything above the `---` line of a level file.
+#[turbo::serialize]
+pub struct LevelHeader {
+    pub name: String,
+    pub music: String,
+    pub enemy_count: usize,
+    pub boss: bool,
+    /// Which boss shows up (`boss: <name>` in the header)
+    pub boss_kind: BossKind,
+    /// Ambient darkness, 0.0 = broad daylight, 1.0 = pitch black
+    pub darkness: f32,
+    /// Swaps the boss's own phases for another plan
+    pub boss_phases: Option<PhasePlan>,
+}
 
-    let grid: Grid = layout
-        .iter()
-        .map(|row| {
-            row.chars()
-                .map(|c| match c {
-                    '#' => Tile { tile_type: TileType::Wall },
-                    '.' => Tile { tile_type: TileType::Floor },
-                    _ => Tile { tile_type: TileType::Floor },
-                })
-                .collect()
-        })
-        .collect();
+#[derive(Debug, PartialEq)]
+pub enum LevelError {
+    MissingHeader(&'static str),
+    BadHeaderLine { line: usize, text: String },
+    BadHeaderValue { key: &'static str, value: String },
+    EmptyLayout,
+    RaggedRow { row: usize, expected: usize, found: usize },
+    UnknownGlyph { row: usize, col: usize, glyph: char },
+    DuplicateMarker { row: usize, col: usize, glyph: char },
+    MissingSpawn,
+    UnexpectedBoss { row: usize, col: usize },
+    BossTooCramped { row: usize, col: usize },
+    PatrolWithoutEnemy { row: usize, col: usize },
+    PatrolUnreachable { row: usize, col: usize },
+    DuplicatePatrol { row: usize, col: usize },
+    SweepWithoutCamera { row: usize, col: usize },
+    DuplicateSweep { row: usize, col: usize },
+}
 
-    let spawn = SpawnInfo {
-        player_spawn: (1, 1),
+impl fmt::Display for LevelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LevelError::MissingHeader(key) => write!(f, "missing header field `{key}`"),
+            LevelError::BadHeaderLine { line, text } => {
+                write!(f, "line {line}: expected `key: value`, got `{text}`")
+            }
+            LevelError::BadHeaderValue { key, value } => {
+                write!(f, "invalid value `{value}` for `{key}`")
+            }
+            LevelError::EmptyLayout => write!(f, "level has no layout rows"),
+            LevelError::RaggedRow { row, expected, found } => write!(
+                f,
+                "layout row {row} is {found} tiles wide, expected {expected}"
+            ),
+            LevelError::UnknownGlyph { row, col, glyph } => {
+                write!(f, "unknown glyph `{glyph}` at row {row}, column {col}")
+            }
+            LevelError::DuplicateMarker { row, col, glyph } => {
+                write!(f, "second `{glyph}` marker at row {row}, column {col}")
+            }
+            LevelError::MissingSpawn => write!(f, "no player spawn (`P`) in layout"),
+            LevelError::UnexpectedBoss { row, col } => write!(
+                f,
+                "boss marker at row {row}, column {col} but header says `boss: false`"
+            ),
+            LevelError::BossTooCramped { row, col } => write!(
+                f,
+                "boss marker at row {row}, column {col} has no room for the boss"
+            ),
+            LevelError::PatrolWithoutEnemy { row, col } => {
+                write!(f, "patrol for row {row}, column {col} but no snowman there")
+            }
+            LevelError::PatrolUnreachable { row, col } => write!(
+                f,
+                "patrol waypoint at row {row}, column {col} can't be walked to"
+            ),
+            LevelError::DuplicatePatrol { row, col } => {
+                write!(f, "second patrol for row {row}, column {col}")
+            }
+            LevelError::SweepWithoutCamera { row, col } => {
+                write!(f, "camera sweep for row {row}, column {col} but no camera there")
+            }
+            LevelError::DuplicateSweep { row, col } => {
+                write!(f, "second camera sweep for row {row}, column {col}")
+            }
+        }
+    }
+}
+
+enum Glyph {
+    Tile(TileType),
+    Player,
+    Enemy(EnemyKind, Option<f32>),
+    Boss,
+    Gift(GiftType),
+    Exit,
+    Cover(CoverKind),
+    Camera,
+    Trap(TrapKind),
+}
+
+fn parse_glyph(glyph: char) -> Option<Glyph> {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    match glyph {
+        '#' => Some(Glyph::Tile(TileType::Wall)),
+        '.' => Some(Glyph::Tile(TileType::Floor)),
+        'P' => Some(Glyph::Player),
+        'E' => Some(Glyph::Enemy(EnemyKind::Snowman, None)),
+        '>' => Some(Glyph::Enemy(EnemyKind::Snowman, Some(0.0))),
+        'v' => Some(Glyph::Enemy(EnemyKind::Snowman, Some(FRAC_PI_2))),
+        '<' => Some(Glyph::Enemy(EnemyKind::Snowman, Some(PI))),
+        '^' => Some(Glyph::Enemy(EnemyKind::Snowman, Some(-FRAC_PI_2))),
+        'S' => Some(Glyph::Enemy(EnemyKind::Sniper, None)),
+        'R' => Some(Glyph::Enemy(EnemyKind::ReindeerHound, None)),
+        'Y' => Some(Glyph::Enemy(EnemyKind::ElfLookout, None)),
+        'A' => Some(Glyph::Enemy(EnemyKind::Armoured, None)),
+        'B' => Some(Glyph::Boss),
+        'L' => Some(Glyph::Gift(GiftType::Life)),
+        'G' => Some(Glyph::Gift(GiftType::Bullet)),
+        'X' => Some(Glyph::Exit),
+        'T' => Some(Glyph::Cover(CoverKind::Tree)),
+        'H' => Some(Glyph::Cover(CoverKind::Presents)),
+        'C' => Some(Glyph::Camera),
+        '~' => Some(Glyph::Trap(TrapKind::Tripwire)),
+        '_' => Some(Glyph::Trap(TrapKind::PressurePlate)),
+        _ => None,
+    }
+}
+
+fn parse_bool(key: &'static str, value: &str) -> Result<bool, LevelError> {
+    match value {
+        "true" | "yes" => Ok(true),
+        "false" | "no" => Ok(false),
+        _ => Err(LevelError::BadHeaderValue { key, value: value.to_string() }),
+    }
+}
+
+fn parse_tile_pos(key: &'static str, value: &str) -> Result<(usize, usize), LevelError> {
+    let bad = || LevelError::BadHeaderValue { key, value: value.to_string() };
+
+    let (x, y) = value.split_once(',').ok_or_else(bad)?;
+    let x = x.trim().parse().map_err(|_| bad())?;
+    let y = y.trim().parse().map_err(|_| bad())?;
+
+    Ok((x, y))
+}
+
+/// `wander` | `loop x,y x,y ...` | `pingpong x,y ...` | `look deg deg ...`
+///
+/// Routes start from the snowman's own tile; look angles are degrees
+/// clockwise from facing right (0 = right, 90 = down).
+fn parse_patrol(post: (usize, usize), value: &str) -> Result<Patrol, LevelError> {
+    let bad = || LevelError::BadHeaderValue { key: "patrol", value: value.to_string() };
+
+    let mut words = value.split_whitespace();
+    let mode = words.next().ok_or_else(bad)?;
+
+    match mode {
+        "wander" => Ok(Patrol::Wander),
+        "loop" | "pingpong" => {
+            let mut route = vec![post];
+            for word in words {
+                route.push(parse_tile_pos("patrol", word)?);
+            }
+            if route.len() < 2 {
+                return Err(bad());
+            }
+
+            Ok(if mode == "loop" { Patrol::Loop(route) } else { Patrol::PingPong(route) })
+        }
+        "look" => {
+            let angles = words
+                .map(|w| w.parse::<f32>().map(f32::to_radians).map_err(|_| bad()))
+                .collect::<Result<Vec<_>, _>>()?;
+            if angles.is_empty() {
+                return Err(bad());
+            }
+
+            Ok(Patrol::LookAround { post, angles })
+        }
+        _ => Err(bad()),
+    }
+}
+
+/// `camera x,y: <from> <to>` – degrees, swept clockwise from `from` to `to`.
+fn parse_sweep(value: &str) -> Result<(f32, f32), LevelError> {
+    let bad = || LevelError::BadHeaderValue { key: "camera", value: value.to_string() };
+
+    let angles = value
+        .split_whitespace()
+        .map(|w| w.parse::<f32>().map_err(|_| bad()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let [from, to] = angles[..] else {
+        return Err(bad());
     };
 
-    (grid, spawn)
+    let arc = (to - from).rem_euclid(360.0);
+    if arc == 0.0 {
+        return Err(bad());
+    }
+
+    Ok((from.to_radians(), arc.to_radians()))
 }
 
+/// Stores a one-per-level marker, rejecting a second copy.
+fn set_marker(
+    slot: &mut Option<(usize, usize)>,
+    (x, y): (usize, usize),
+    glyph: char,
+) -> Result<(), LevelError> {
+    if slot.is_some() {
+        return Err(LevelError::DuplicateMarker { row: y, col: x, glyph });
+    }
+    *slot = Some((x, y));
+    Ok(())
+}
 
-pub fn load_level_2() -> (Grid, SpawnInfo) {
-    let layout = vec![
-        "########################",
-        "#..........#..........#",
-        "#..######..#..######...#",
-        "#..#....#..#..#....#..#",
-        "#..#....#......#....#.#",
-        "#..######..######....#.#",
-        "#..........#..........#",
-        "######..########..######",
-        "#..........#..........#",
-        "#..######..#..#.####...#",
-        "#..#....#..#..#....#..#",
-        "#..#....#......#....#.#",
-        "#..######..######....#.#",
-        "#..........#..........#",
-        "########################",
-    ];
+/// Parses a level file: `key: value` header lines, a `---` separator,
+/// then one line of glyphs per tile row. Marker glyphs (`P`, `E`, `B`,
+/// `L`, `G`, `X`, `T`, `H`, facing arrows, enemy kinds `S` `R` `Y` `A`, camera `C`,
+/// traps `~` `_`) sit on floor tiles; `patrol x,y:` header lines give the enemy on
+/// tile `x,y` a route and `camera x,y:` lines limit that camera's sweep.
+pub fn parse_level(source: &str) -> Result<(LevelHeader, Grid, SpawnInfo), LevelError> {
+    let mut name = None;
+    let mut music = None;
+    let mut enemy_count: Option<usize> = None;
+    let mut boss = None;
+    let mut boss_kind = None;
+    let mut darkness = None;
+    let mut boss_phases = None;
+    let mut patrols = Vec::new();
+    let mut sweeps = Vec::new();
+
+    let mut lines = source.lines().enumerate();
+
+    // ─── HEADER ───
+    for (i, raw) in lines.by_ref() {
+        let line = raw.trim();
+
+        if line == "---" {
+            break;
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(LevelError::BadHeaderLine { line: i + 1, text: line.to_string() });
+        };
+        let value = value.trim();
+
+        match key.trim() {
+            "name" => name = Some(value.to_string()),
+            "music" => music = Some(value.to_string()),
+            "enemies" => {
+                enemy_count = Some(value.parse().map_err(|_| LevelError::BadHeaderValue {
+                    key: "enemies",
+                    value: value.to_string(),
+                })?);
+            }
+            // `true` / `false`, or the name of a boss (which implies `true`)
+            "boss" => match BossKind::from_name(value) {
+                Some(kind) => {
+                    boss = Some(true);
+                    boss_kind = Some(kind);
+                }
+                None => boss = Some(parse_bool("boss", value)?),
+            },
+            "darkness" => {
+                let bad = || LevelError::BadHeaderValue { key: "darkness", value: value.to_string() };
+                let level: f32 = value.parse().map_err(|_| bad())?;
+                if !(0.0..=1.0).contains(&level) {
+                    return Err(bad());
+                }
+                darkness = Some(level);
+            }
+            "boss_phases" => {
+                boss_phases = Some(PhasePlan::from_name(value).ok_or_else(|| {
+                    LevelError::BadHeaderValue { key: "boss_phases", value: value.to_string() }
+                })?);
+            }
+            key if key.starts_with("patrol ") => {
+                let post = parse_tile_pos("patrol", &key["patrol ".len()..])?;
+                if patrols.iter().any(|&(other, _)| other == post) {
+                    return Err(LevelError::DuplicatePatrol { row: post.1, col: post.0 });
+                }
+                patrols.push((post, parse_patrol(post, value)?));
+            }
+            key if key.starts_with("camera ") => {
+                let tile = parse_tile_pos("camera", &key["camera ".len()..])?;
+                if sweeps.iter().any(|&(other, _)| other == tile) {
+                    return Err(LevelError::DuplicateSweep { row: tile.1, col: tile.0 });
+                }
+                sweeps.push((tile, parse_sweep(value)?));
+            }
+            _ => {
+                return Err(LevelError::BadHeaderLine { line: i + 1, text: line.to_string() });
+            }
+        }
+    }
+
+    let name = name.ok_or(LevelError::MissingHeader("name"))?;
+    let music = music.ok_or(LevelError::MissingHeader("music"))?;
+
+    // ─── LAYOUT ───
+    let mut grid: Grid = Vec::new();
+    let mut player_spawn = None;
+    let mut boss_spawn = None;
+    let mut exit = None;
+    let mut enemies = Vec::new();
+    let mut gifts = Vec::new();
+    let mut cover = Vec::new();
+    let mut cameras = Vec::new();
+    let mut traps = Vec::new();
+
+    for (_, raw) in lines {
+        let row = raw.trim_end();
+        if row.is_empty() {
+            continue;
+        }
 
-    let grid: Grid = layout
-        .iter()
-        .map(|row| {
-            row.chars()
-                .map(|c| match c {
-                    '#' => Tile { tile_type: TileType::Wall },
-                    '.' => Tile { tile_type: TileType::Floor },
-                    _ => Tile { tile_type: TileType::Floor },
-                })
-                .collect()
-        })
-        .collect();
+        let y = grid.len();
+        let mut tiles = Vec::with_capacity(row.len());
+
+        for (x, glyph) in row.chars().enumerate() {
+            let Some(parsed) = parse_glyph(glyph) else {
+                return Err(LevelError::UnknownGlyph { row: y, col: x, glyph });
+            };
+
+            let tile_type = match parsed {
+                Glyph::Tile(tile_type) => tile_type,
+                Glyph::Player => {
+                    set_marker(&mut player_spawn, (x, y), glyph)?;
+                    TileType::Floor
+                }
+                Glyph::Enemy(kind, facing) => {
+                    enemies.push(EnemySpawn { kind, tile: (x, y), facing, patrol: Patrol::Wander });
+                    TileType::Floor
+                }
+                Glyph::Boss => {
+                    set_marker(&mut boss_spawn, (x, y), glyph)?;
+                    TileType::Floor
+                }
+                Glyph::Gift(kind) => {
+                    gifts.push(((x, y), kind));
+                    TileType::Floor
+                }
+                Glyph::Exit => {
+                    set_marker(&mut exit, (x, y), glyph)?;
+                    TileType::Floor
+                }
+                Glyph::Cover(kind) => {
+                    cover.push(((x, y), kind));
+                    TileType::Floor
+                }
+                Glyph::Camera => {
+                    cameras.push(CameraSpawn { tile: (x, y), sweep: None });
+                    TileType::Floor
+                }
+                Glyph::Trap(kind) => {
+                    traps.push(((x, y), kind));
+                    TileType::Floor
+                }
+            };
+
+            tiles.push(Tile { tile_type });
+        }
+
+        if let Some(first) = grid.first()
+            && first.len() != tiles.len()
+        {
+            return Err(LevelError::RaggedRow {
+                row: y,
+                expected: first.len(),
+                found: tiles.len(),
+            });
+        }
+
+        grid.push(tiles);
+    }
+
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(LevelError::EmptyLayout);
+    }
+
+    let player_spawn = player_spawn.ok_or(LevelError::MissingSpawn)?;
+
+    // Boss: explicit header wins, otherwise a `B` marker implies one
+    let boss = boss.unwrap_or(boss_spawn.is_some());
+    let boss_kind = boss_kind.unwrap_or(BossKind::NightBorne);
+
+    if let Some((x, y)) = boss_spawn {
+        if !boss {
+            return Err(LevelError::UnexpectedBoss { row: y, col: x });
+        }
+
+        let (wx, wy) = tile_to_world(x, y);
+        if !is_spawn_position_valid(&grid, wx, wy, boss_kind.def().sprite_size) {
+            return Err(LevelError::BossTooCramped { row: y, col: x });
+        }
+    }
+
+    // ─── PATROLS ───
+    for (post, patrol) in patrols {
+        let Some(enemy) = enemies.iter_mut().find(|e| e.tile == post) else {
+            return Err(LevelError::PatrolWithoutEnemy { row: post.1, col: post.0 });
+        };
+
+        let reachable = compute_reachable(&grid, post);
+        let waypoints: &[(usize, usize)] = match &patrol {
+            Patrol::Loop(route) | Patrol::PingPong(route) => route,
+            _ => &[],
+        };
+
+        for &(x, y) in waypoints {
+            if y >= grid.len() || x >= grid[0].len() || !reachable[y][x] {
+                return Err(LevelError::PatrolUnreachable { row: y, col: x });
+            }
+        }
+
+        enemy.patrol = patrol;
+    }
+
+    // ─── CAMERA SWEEPS ───
+    for (tile, sweep) in sweeps {
+        let Some(camera) = cameras.iter_mut().find(|c| c.tile == tile) else {
+            return Err(LevelError::SweepWithoutCamera { row: tile.1, col: tile.0 });
+        };
+        camera.sweep = Some(sweep);
+    }
+
+    let header = LevelHeader {
+        name,
+        music,
+        // Marked snowmen count toward the total; the rest are random
+        enemy_count: enemy_count.unwrap_or(0).max(enemies.len()),
+        boss,
+        boss_kind,
+        darkness: darkness.unwrap_or(DEFAULT_DARKNESS),
+        boss_phases,
+    };
 
     let spawn = SpawnInfo {
-        player_spawn: (1, 1),
+        player_spawn,
+        enemies,
+        boss: boss_spawn,
+        gifts,
+        exit,
+        cover,
+        cameras,
+        traps,
     };
 
-    (grid, spawn)
+    Ok((header, grid, spawn))
 }
 
+/// Loads a bundled level (1-based). Past the last file the list loops
+/// around, so the run keeps going with the same maps.
+pub fn load_level_by_index(level: u32) -> (LevelHeader, Grid, SpawnInfo) {
+    let index = (level.max(1) - 1) as usize % LEVEL_FILES.len();
+    let (file, source) = LEVEL_FILES[index];
 
-pub fn load_level_by_index(level: u32) -> (Grid, SpawnInfo) {
-    match level {
-        1 => load_level_1(),
-        2 => load_level_2(),
-        _ => load_level_1(),
-    }
+    parse_level(source).unwrap_or_else(|err| panic!("levels/{file}: {err}"))
 }
 
 use std::collections::VecDeque;
@@ -131,6 +540,56 @@ pub fn compute_reachable(
 }
 
 
+/// Floor tiles reachable from `start` in at most `max_steps` moves,
+/// with the number of steps to each.
+pub fn tiles_within_steps(
+    grid: &Grid,
+    start: (usize, usize),
+    max_steps: usize,
+) -> Vec<((usize, usize), usize)> {
+    let h = grid.len();
+    let w = grid[0].len();
+
+    let mut visited = vec![vec![false; w]; h];
+    let mut queue = VecDeque::new();
+    let mut found = Vec::new();
+
+    if start.1 >= h || start.0 >= w {
+        return found;
+    }
+
+    queue.push_back((start, 0));
+    visited[start.1][start.0] = true;
+
+    while let Some(((x, y), steps)) = queue.pop_front() {
+        found.push(((x, y), steps));
+
+        if steps == max_steps {
+            continue;
+        }
+
+        let neighbors = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+
+        for (nx, ny) in neighbors {
+            if nx < w && ny < h
+                && !visited[ny][nx]
+                && grid[ny][nx].tile_type == TileType::Floor
+            {
+                visited[ny][nx] = true;
+                queue.push_back(((nx, ny), steps + 1));
+            }
+        }
+    }
+
+    found
+}
+
+
 pub fn is_wall(grid: &Grid, x: f32, y: f32) -> bool {
     let tx = (x as i32 / TILE_SIZE) as usize;
     let ty = (y as i32 / TILE_SIZE) as usize;
@@ -225,6 +684,19 @@ pub fn find_path(
     start: (usize, usize),
     goal: (usize, usize),
 ) -> Option<Vec<(usize, usize)>> {
+    find_path_avoiding(grid, start, goal, &[])
+}
+
+/// Like `find_path`, but stepping onto an `avoid` tile costs
+/// `AVOID_COST` steps – the route goes round them if there's a way.
+pub fn find_path_avoiding(
+    grid: &Grid,
+    start: (usize, usize),
+    goal: (usize, usize),
+    avoid: &[(usize, usize)],
+) -> Option<Vec<(usize, usize)>> {
+    const AVOID_COST: i32 = 10;
+
     let mut open = BinaryHeap::new();
     let mut came_from: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
     let mut cost_so_far: HashMap<(usize, usize), i32> = HashMap::new();
@@ -266,8 +738,9 @@ pub fn find_path(
                 continue;
             }
 
-            let new_cost = cost_so_far[&current.pos] + 1;
             let next = (nx, ny);
+            let step = if avoid.contains(&next) { AVOID_COST } else { 1 };
+            let new_cost = cost_so_far[&current.pos] + step;
 
             if !cost_so_far.contains_key(&next)
                 || new_cost < cost_so_far[&next]
@@ -321,3 +794,138 @@ pub fn find_nearest_floor(
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "name: Test\nmusic: snowmusic\n";
+
+    /// Header lines + `---` + layout rows.
+    fn level(header: &str, rows: &[&str]) -> String {
+        format!("{header}---\n{}\n", rows.join("\n"))
+    }
+
+    fn parse_err(source: &str) -> LevelError {
+        match parse_level(source) {
+            Ok(_) => panic!("level parsed but should have failed:\n{source}"),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn bundled_levels_parse() {
+        for (file, source) in LEVEL_FILES {
+            if let Err(err) = parse_level(source) {
+                panic!("levels/{file}: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn minimal_level_parses() {
+        let (header, grid, spawn) = parse_level(&level(HEADER, &["#####", "#P.E#", "#####"])).unwrap();
+
+        assert_eq!(header.name, "Test");
+        assert_eq!((grid.len(), grid[0].len()), (3, 5));
+        assert_eq!(spawn.player_spawn, (1, 1));
+        assert_eq!(spawn.enemies.len(), 1);
+    }
+
+    #[test]
+    fn missing_header() {
+        let source = level("name: Test\n", &["###", "#P#", "###"]);
+        assert_eq!(parse_err(&source), LevelError::MissingHeader("music"));
+    }
+
+    #[test]
+    fn bad_header_line() {
+        let source = level(&format!("{HEADER}no colon here\n"), &["###", "#P#", "###"]);
+        assert_eq!(
+            parse_err(&source),
+            LevelError::BadHeaderLine { line: 3, text: "no colon here".to_string() }
+        );
+    }
+
+    #[test]
+    fn bad_header_value() {
+        let source = level(&format!("{HEADER}enemies: lots\n"), &["###", "#P#", "###"]);
+        assert_eq!(
+            parse_err(&source),
+            LevelError::BadHeaderValue { key: "enemies", value: "lots".to_string() }
+        );
+    }
+
+    #[test]
+    fn empty_layout() {
+        assert_eq!(parse_err(&level(HEADER, &[])), LevelError::EmptyLayout);
+    }
+
+    #[test]
+    fn ragged_row() {
+        let source = level(HEADER, &["####", "#P#", "####"]);
+        assert_eq!(parse_err(&source), LevelError::RaggedRow { row: 1, expected: 4, found: 3 });
+    }
+
+    #[test]
+    fn unknown_glyph() {
+        let source = level(HEADER, &["####", "#PQ#", "####"]);
+        assert_eq!(parse_err(&source), LevelError::UnknownGlyph { row: 1, col: 2, glyph: 'Q' });
+    }
+
+    #[test]
+    fn duplicate_marker() {
+        let source = level(HEADER, &["####", "#PP#", "####"]);
+        assert_eq!(parse_err(&source), LevelError::DuplicateMarker { row: 1, col: 2, glyph: 'P' });
+    }
+
+    #[test]
+    fn missing_spawn() {
+        let source = level(HEADER, &["####", "#..#", "####"]);
+        assert_eq!(parse_err(&source), LevelError::MissingSpawn);
+    }
+
+    #[test]
+    fn unexpected_boss() {
+        let source = level(&format!("{HEADER}boss: false\n"), &["#####", "#P.B#", "#####"]);
+        assert_eq!(parse_err(&source), LevelError::UnexpectedBoss { row: 1, col: 3 });
+    }
+
+    #[test]
+    fn boss_too_cramped() {
+        let source = level(&format!("{HEADER}boss: true\n"), &["#####", "#P.B#", "#####"]);
+        assert_eq!(parse_err(&source), LevelError::BossTooCramped { row: 1, col: 3 });
+    }
+
+    #[test]
+    fn patrol_without_enemy() {
+        let source = level(&format!("{HEADER}patrol 2,1: pingpong 3,1\n"), &["#####", "#P..#", "#####"]);
+        assert_eq!(parse_err(&source), LevelError::PatrolWithoutEnemy { row: 1, col: 2 });
+    }
+
+    #[test]
+    fn patrol_unreachable() {
+        let source = level(&format!("{HEADER}patrol 3,1: pingpong 3,0\n"), &["#####", "#P.E#", "#####"]);
+        assert_eq!(parse_err(&source), LevelError::PatrolUnreachable { row: 0, col: 3 });
+    }
+
+    #[test]
+    fn duplicate_patrol() {
+        let header = format!("{HEADER}patrol 3,1: pingpong 2,1\npatrol 3,1: wander\n");
+        let source = level(&header, &["#####", "#P.E#", "#####"]);
+        assert_eq!(parse_err(&source), LevelError::DuplicatePatrol { row: 1, col: 3 });
+    }
+
+    #[test]
+    fn sweep_without_camera() {
+        let source = level(&format!("{HEADER}camera 2,1: 0 90\n"), &["####", "#P.#", "####"]);
+        assert_eq!(parse_err(&source), LevelError::SweepWithoutCamera { row: 1, col: 2 });
+    }
+
+    #[test]
+    fn duplicate_sweep() {
+        let header = format!("{HEADER}camera 2,1: 0 90\ncamera 2,1: 90 180\n");
+        let source = level(&header, &["####", "#PC#", "####"]);
+        assert_eq!(parse_err(&source), LevelError::DuplicateSweep { row: 1, col: 2 });
+    }
+}
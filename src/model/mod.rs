@@ -3,12 +3,18 @@ pub use level::*;
 pub mod tile;
 pub mod level;
 pub mod boss;
+pub mod boss_phase;
+pub mod boss_def;
+pub mod enemy_def;
+pub mod squad;
 
 pub mod enemy;
 pub use enemy::*;
 pub use boss::*;
-
-use turbo::*;
+pub use boss_phase::*;
+pub use boss_def::*;
+pub use enemy_def::*;
+pub use squad::*;
 
 #[turbo::serialize]
 #[derive(PartialEq, Copy)]
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use crate::model::enemy::wrap_angle;
+use crate::model::{find_path, tiles_within_steps, Enemy, EnemyState};
+use crate::{Grid, TileType};
+
+pub const SQUAD_REPLAN_FRAMES: u32 = 20; // how often `coordinate_squad` runs
+
+const DIRECT_STEPS: usize = 4;            // this close, everyone just goes for him
+const FLANK_STEPS: (usize, usize) = (2, 4);   // ring round Santa flankers aim for
+const CUT_OFF_STEPS: (usize, usize) = (5, 9); // chokepoints further out
+const PLAN_STEPS: usize = 200;            // far enough to cover any level
+const ORDER_SLACK: usize = 2;             // orders stay valid this far outside their band
+
+/// What a chasing snowman has been told to do.
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum SquadRole {
+    Direct, // straight at Santa (the original behaviour)
+    Flank,  // come at him from another side, then close in
+    CutOff, // wait in a corridor he'd have to run through
+    Hold,   // guard the exit
+}
+
+impl SquadRole {
+    /// Whether it waits once it gets there instead of closing in
+    pub fn holds(self) -> bool {
+        matches!(self, SquadRole::CutOff | SquadRole::Hold)
+    }
+}
+
+/// Splits the snowmen chasing Santa so they don't all queue down the
+/// same corridor: the nearest goes straight for him, the rest flank,
+/// block chokepoints and hold the exit – each from a different side,
+/// steering clear of the lead chaser's route and Santa himself.
+pub fn coordinate_squad(
+    enemies: &mut [Enemy],
+    map: &Grid,
+    player_tile: (usize, usize),
+    exit: Option<(usize, usize)>,
+) {
+    let steps_from_player: HashMap<(usize, usize), usize> =
+        tiles_within_steps(map, player_tile, PLAN_STEPS).into_iter().collect();
+    let steps_to = |tile| steps_from_player.get(&tile).copied();
+
+    // Only mobile chasers take orders; everyone else has none
+    let mut chasers = Vec::new();
+    for (i, enemy) in enemies.iter_mut().enumerate() {
+        if enemy.alive && enemy.state == EnemyState::Chasing && !enemy.def().stationary {
+            chasers.push((i, steps_to(enemy.tile()).unwrap_or(usize::MAX)));
+        } else {
+            enemy.assign_squad(SquadRole::Direct, None);
+        }
+    }
+    chasers.sort_by_key(|&(_, steps)| steps);
+
+    // Everyone with orders goes round the lead's route and Santa's tile
+    let mut avoid = chasers
+        .first()
+        .and_then(|&(lead, _)| find_path(map, enemies[lead].tile(), player_tile))
+        .unwrap_or_default();
+    avoid.push(player_tile);
+    let off_route = |tile| !avoid.contains(&tile);
+
+    let mut covered: Vec<f32> = Vec::new(); // directions from Santa already taken
+    let mut exit_held = false;
+    let mut roles = [SquadRole::Flank, SquadRole::Hold, SquadRole::CutOff].iter().copied().cycle();
+
+    for (n, &(i, steps)) in chasers.iter().enumerate() {
+        let enemy = &mut enemies[i];
+
+        // The lead chaser and anyone already close go straight for him
+        if n == 0 || steps <= DIRECT_STEPS {
+            enemy.assign_squad(SquadRole::Direct, None);
+            covered.push(direction(player_tile, enemy.tile()));
+            continue;
+        }
+
+        // Orders that still make sense stand – no reshuffling every replan
+        let role = enemy.squad_role();
+        if let Some(goal) = enemy.squad_goal()
+            && still_valid(role, goal, steps_to(goal), exit)
+        {
+            exit_held |= role == SquadRole::Hold;
+            covered.push(direction(player_tile, goal));
+            enemy.avoid_on_the_way(&avoid);
+            continue;
+        }
+
+        let mut role = roles.next().unwrap_or(SquadRole::Flank);
+        if role == SquadRole::Hold && (exit_held || exit.and_then(steps_to).is_none()) {
+            role = roles.next().unwrap_or(SquadRole::Flank);
+        }
+
+        let goal = match role {
+            SquadRole::Hold => exit,
+            SquadRole::Flank => spread_out(&steps_from_player, player_tile, FLANK_STEPS, &covered, off_route),
+            SquadRole::CutOff => {
+                spread_out(&steps_from_player, player_tile, CUT_OFF_STEPS, &covered, |tile| {
+                    off_route(tile) && is_chokepoint(map, tile)
+                })
+                .or_else(|| spread_out(&steps_from_player, player_tile, CUT_OFF_STEPS, &covered, off_route))
+            }
+            SquadRole::Direct => None,
+        };
+
+        match goal {
+            Some(goal) => {
+                exit_held |= role == SquadRole::Hold;
+                covered.push(direction(player_tile, goal));
+                enemy.assign_squad(role, Some(goal));
+                enemy.avoid_on_the_way(&avoid);
+            }
+            None => enemy.assign_squad(SquadRole::Direct, None),
+        }
+    }
+}
+
+fn still_valid(
+    role: SquadRole,
+    goal: (usize, usize),
+    steps: Option<usize>,
+    exit: Option<(usize, usize)>,
+) -> bool {
+    let in_band = |(min, max): (usize, usize)| {
+        steps.is_some_and(|s| s + ORDER_SLACK >= min && s <= max + ORDER_SLACK)
+    };
+
+    match role {
+        SquadRole::Direct => false,
+        SquadRole::Flank => in_band(FLANK_STEPS),
+        SquadRole::CutOff => in_band(CUT_OFF_STEPS),
+        SquadRole::Hold => exit == Some(goal),
+    }
+}
+
+/// The tile `min..=max` steps from Santa that's furthest (by angle)
+/// from every direction the squad already covers.
+fn spread_out(
+    steps_from_player: &HashMap<(usize, usize), usize>,
+    player_tile: (usize, usize),
+    (min, max): (usize, usize),
+    covered: &[f32],
+    accept: impl Fn((usize, usize)) -> bool,
+) -> Option<(usize, usize)> {
+    let gap = |tile| {
+        let dir = direction(player_tile, tile);
+        covered
+            .iter()
+            .map(|&taken| wrap_angle(dir - taken).abs())
+            .fold(std::f32::consts::PI, f32::min)
+    };
+
+    steps_from_player
+        .iter()
+        .filter(|&(&tile, &steps)| steps >= min && steps <= max && accept(tile))
+        .map(|(&tile, _)| (tile, gap(tile)))
+        // Ties broken by tile so every frame picks the same one
+        .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(tile, _)| tile)
+}
+
+/// A one-tile-wide corridor: walls on both sides along one axis,
+/// open along the other.
+fn is_chokepoint(map: &Grid, (x, y): (usize, usize)) -> bool {
+    let wall = |x: usize, y: usize| {
+        map.get(y)
+            .and_then(|row| row.get(x))
+            .is_none_or(|tile| tile.tile_type == TileType::Wall)
+    };
+    let (left, right) = (x == 0 || wall(x - 1, y), wall(x + 1, y));
+    let (up, down) = (y == 0 || wall(x, y - 1), wall(x, y + 1));
+
+    (left && right && !up && !down) || (up && down && !left && !right)
+}
+
+fn direction(from: (usize, usize), to: (usize, usize)) -> f32 {
+    let dx = to.0 as f32 - from.0 as f32;
+    let dy = to.1 as f32 - from.1 as f32;
+    dy.atan2(dx)
+}
@@ -0,0 +1,84 @@
+use turbo::*;
+use crate::{Grid, is_wall};
+
+// ─── Loudness radii (px) ───
+pub const FOOTSTEP_NOISE_PER_SPEED: f32 = 30.0; // full walking speed ≈ 72px
+pub const SNOWBALL_IMPACT_NOISE: f32 = 150.0;
+pub const GUNSHOT_NOISE: f32 = 320.0;
+pub const GIFT_PICKUP_NOISE: f32 = 90.0;
+
+const WALL_DAMPING: f32 = 0.5; // each wall tile in the way halves the radius
+const RIPPLE_FRAMES: u32 = 20;
+
+#[turbo::serialize]
+pub struct Noise {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    timer: u32, // ripple lifetime (visual only)
+}
+
+impl Noise {
+    pub fn new(x: f32, y: f32, radius: f32) -> Self {
+        Self {
+            x,
+            y,
+            radius,
+            timer: RIPPLE_FRAMES,
+        }
+    }
+
+    /// Whether a listener at (lx, ly) hears this noise. Sound carries
+    /// freely along corridors but loses most of its reach through walls.
+    pub fn heard_at(&self, map: &Grid, lx: f32, ly: f32) -> bool {
+        let dx = lx - self.x;
+        let dy = ly - self.y;
+        let dist = (dx * dx + dy * dy).sqrt();
+
+        if dist > self.radius {
+            return false;
+        }
+
+        // Count wall tiles crossed on the straight line
+        let steps = (dist / 4.0).ceil() as i32;
+        let mut walls = 0;
+        let mut last_wall_tile = None;
+
+        for i in 0..steps {
+            let t = i as f32 / steps as f32;
+            let rx = self.x + dx * t;
+            let ry = self.y + dy * t;
+
+            if is_wall(map, rx, ry) {
+                let tile = (rx as i32 / crate::TILE_SIZE, ry as i32 / crate::TILE_SIZE);
+                if last_wall_tile != Some(tile) {
+                    walls += 1;
+                    last_wall_tile = Some(tile);
+                }
+            }
+        }
+
+        dist <= self.radius * WALL_DAMPING.powi(walls)
+    }
+
+    /// Returns false once the ripple has faded.
+    pub fn update(&mut self) -> bool {
+        self.timer = self.timer.saturating_sub(1);
+        self.timer > 0
+    }
+
+    pub fn draw(&self) {
+        let t = 1.0 - self.timer as f32 / RIPPLE_FRAMES as f32;
+        let r = (self.radius * t) as i32;
+        let alpha = ((1.0 - t) * 120.0) as u32;
+
+        circ!(
+            x = self.x as i32 - r,
+            y = self.y as i32 - r,
+            d = (r * 2) as u32,
+            color = 0x00000000,
+            border_size = 1,
+            border_color = 0xffffff00 | alpha
+        );
+    }
+}
@@ -1,18 +1,312 @@
     use turbo::*;
     use crate::{Grid, is_wall};
-    use crate::model::Enemy; // ✅ ADD THIS
+    use crate::noise::FOOTSTEP_NOISE_PER_SPEED;
+    use crate::projectile::ProjectileKind;
+    use crate::model::BossKind;
 
     #[turbo::serialize]
 #[derive(Copy,PartialEq)]
 pub enum WeaponMode {
     Snowball,
     Gun,
+    CandyCane,
+}
+
+impl WeaponMode {
+    /// What it shoots – `None` for melee
+    pub fn projectile(self) -> Option<ProjectileKind> {
+        match self {
+            WeaponMode::Snowball => Some(ProjectileKind::Snowball),
+            WeaponMode::Gun => Some(ProjectileKind::GunBullet),
+            WeaponMode::CandyCane => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WeaponMode::Snowball => "SNOWBALL",
+            WeaponMode::Gun => "GUN",
+            WeaponMode::CandyCane => "CANDY CANE",
+        }
+    }
+}
+
+/// One weapon Santa carries
+#[turbo::serialize]
+pub struct WeaponSlot {
+    pub weapon: WeaponMode,
+    pub loaded: u32,
+    pub reserve: Option<u32>, // None = endless (snowballs)
+}
+
+/// Santa's weapons, ammo and the shared fire / reload timers.
+/// Q cycles, R reloads.
+#[turbo::serialize]
+pub struct Inventory {
+    pub slots: Vec<WeaponSlot>,
+    pub current: usize,
+    fire_cooldown: u32,
+    pub reload_timer: u32, // > 0 while reloading
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self {
+            slots: vec![
+                WeaponSlot {
+                    weapon: WeaponMode::Snowball,
+                    loaded: ProjectileKind::Snowball.def().magazine,
+                    reserve: None,
+                },
+                WeaponSlot {
+                    weapon: WeaponMode::CandyCane,
+                    loaded: 0,
+                    reserve: None,
+                },
+            ],
+            current: 0,
+            fire_cooldown: 0,
+            reload_timer: 0,
+        }
+    }
+
+    pub fn weapon(&self) -> WeaponMode {
+        self.slots[self.current].weapon
+    }
+
+    pub fn slot(&self) -> &WeaponSlot {
+        &self.slots[self.current]
+    }
+
+    /// Next weapon in the list (cancels a reload)
+    pub fn cycle(&mut self) {
+        self.current = (self.current + 1) % self.slots.len();
+        self.reload_timer = 0;
+    }
+
+    /// Gives ammo, adding the weapon if Santa didn't have it yet.
+    /// Returns true if the weapon is new.
+    pub fn add_ammo(&mut self, weapon: WeaponMode, rounds: u32) -> bool {
+        if let Some(slot) = self.slots.iter_mut().find(|s| s.weapon == weapon) {
+            if let Some(reserve) = &mut slot.reserve {
+                *reserve += rounds;
+            }
+            return false;
+        }
+
+        let magazine = weapon.projectile().map_or(0, |p| p.def().magazine);
+        let loaded = rounds.min(magazine);
+
+        self.slots.push(WeaponSlot {
+            weapon,
+            loaded,
+            reserve: Some(rounds - loaded),
+        });
+        true
+    }
+
+    pub fn select(&mut self, weapon: WeaponMode) {
+        if let Some(i) = self.slots.iter().position(|s| s.weapon == weapon) {
+            self.current = i;
+            self.reload_timer = 0;
+        }
+    }
+
+    /// Starts reloading the current weapon if it's ranged, not full,
+    /// and there's ammo to load.
+    pub fn start_reload(&mut self) {
+        let slot = &self.slots[self.current];
+        let Some(kind) = slot.weapon.projectile() else {
+            return;
+        };
+
+        if self.reload_timer > 0
+            || slot.loaded >= kind.def().magazine
+            || slot.reserve == Some(0)
+        {
+            return;
+        }
+
+        self.reload_timer = kind.def().reload_frames;
+    }
+
+    /// 0.0 → 1.0 through the current reload
+    pub fn reload_progress(&self) -> f32 {
+        match self.weapon().projectile() {
+            Some(kind) if self.reload_timer > 0 => {
+                1.0 - self.reload_timer as f32 / kind.def().reload_frames as f32
+            }
+            _ => 0.0,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.fire_cooldown = self.fire_cooldown.saturating_sub(1);
+
+        if self.reload_timer == 0 {
+            return;
+        }
+
+        self.reload_timer -= 1;
+        if self.reload_timer > 0 {
+            return;
+        }
+
+        // ✅ Reload done – top up from the reserve
+        let slot = &mut self.slots[self.current];
+        let Some(kind) = slot.weapon.projectile() else {
+            return;
+        };
+
+        let wanted = kind.def().magazine - slot.loaded;
+        let taken = match &mut slot.reserve {
+            Some(reserve) => {
+                let taken = wanted.min(*reserve);
+                *reserve -= taken;
+                taken
+            }
+            None => wanted,
+        };
+        slot.loaded += taken;
+    }
+
+    /// Uses one round of the current ranged weapon. Returns what to
+    /// launch, or `None` if it can't fire this frame. An empty magazine
+    /// starts a reload; a weapon with nothing left at all is dropped.
+    pub fn try_fire(&mut self) -> Option<ProjectileKind> {
+        let kind = self.weapon().projectile()?;
+
+        if self.fire_cooldown > 0 || self.reload_timer > 0 {
+            return None;
+        }
+
+        let slot = &mut self.slots[self.current];
+        if slot.loaded == 0 {
+            self.start_reload();
+            return None;
+        }
+
+        slot.loaded -= 1;
+        self.fire_cooldown = kind.def().fire_delay;
+
+        if slot.loaded == 0 {
+            if slot.reserve == Some(0) {
+                // 🗑 Out of ammo for good
+                self.slots.remove(self.current);
+                self.current = 0;
+            } else {
+                self.start_reload();
+            }
+        }
+
+        Some(kind)
+    }
+}
+
+/// What last hurt Santa – the lose screen says who got him
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum DamageSource {
+    Snowman,
+    Hound,
+    Boss(BossKind),
+}
+
+impl DamageSource {
+    pub fn lose_message(self) -> &'static str {
+        match self {
+            DamageSource::Snowman => "Caught by a snowman",
+            DamageSource::Hound => "Run down by a reindeer hound",
+            DamageSource::Boss(kind) => kind.def().lose_message,
+        }
+    }
+}
+
+/// Where Santa's aim comes from right now
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum AimMode {
+    Movement, // follows the way he walks
+    Keys,     // WASD, 8-way
+    Mouse,    // towards the pointer
+}
+
+/// Movement style – trades speed for stealth.
+/// Hold C to sneak, SHIFT to sprint.
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum Gait {
+    Sneak,
+    Walk,
+    Sprint,
+}
+
+impl Gait {
+    fn max_speed(self) -> f32 {
+        match self {
+            Gait::Sneak => 1.2,
+            Gait::Walk => 2.4,
+            Gait::Sprint => 3.6,
+        }
+    }
+
+    /// Multiplier on `Player::speed`
+    fn acceleration(self) -> f32 {
+        match self {
+            Gait::Sneak => 0.5,
+            Gait::Walk => 1.0,
+            Gait::Sprint => 1.3,
+        }
+    }
+
+    /// Multiplier on footstep loudness
+    fn noise(self) -> f32 {
+        match self {
+            Gait::Sneak => 0.3,
+            Gait::Walk => 1.0,
+            Gait::Sprint => 2.0,
+        }
+    }
+
+    /// Multiplier on how far away snowmen can pick Santa out
+    pub fn visibility(self) -> f32 {
+        match self {
+            Gait::Sneak => 0.75,
+            Gait::Walk => 1.0,
+            Gait::Sprint => 1.25,
+        }
+    }
+
+    fn anim_rate(self) -> f32 {
+        match self {
+            Gait::Sneak => 0.5,
+            Gait::Walk => 1.0,
+            Gait::Sprint => 1.6,
+        }
+    }
 }
 
     // ───────── Player sizing constants ─────────
     const SPRITE_SIZE: f32 = 32.0;
     const COLLISION_SIZE: f32 = 24.0;
     const COLLISION_OFFSET: f32 = (SPRITE_SIZE - COLLISION_SIZE) / 2.0;
+    const FOOTSTEP_INTERVAL: u32 = 18; // frames between footstep noises
+    const AIM_HOLD_FRAMES: u32 = 90;   // WASD aim sticks this long after release
+    const RETICLE_DISTANCE: f32 = 44.0;
+
+    // ───────── Getting hurt ─────────
+    const IFRAMES: u32 = 60;           // invulnerable this long after a hit
+    const HIT_FLASH_FRAMES: u32 = 8;   // red tint at the start of the i-frames
+    const HIT_KNOCKBACK: f32 = 5.0;    // px/frame, decays
+    const KNOCKBACK_DECAY: f32 = 0.75;
+    const HIT_SHAKE: f32 = 6.0;        // camera shake per point of damage
+
+    // ───────── Candy cane melee ─────────
+    const MELEE_SWING_FRAMES: u32 = 14;
+    const MELEE_IMPACT_FRAME: u32 = 7; // swing_timer value when the hit lands
+    const MELEE_COOLDOWN: u32 = 28;    // frames from swing start to the next
+    const MELEE_RANGE: f32 = 40.0;     // centre to centre
+    const MELEE_ARC: f32 = 110.0;      // degrees, centred on facing
     
 
     #[turbo::serialize]
@@ -30,8 +324,26 @@ pub enum WeaponMode {
         // ─── Facing & melee ───
         pub facing: (f32, f32), // normalized direction
         pub is_moving: bool,
-        pub weapon: WeaponMode,
 
+        // ─── Aim (what every weapon fires along) ───
+        pub aim: (f32, f32), // normalized direction
+        pub aim_mode: AimMode,
+        aim_hold: u32,
+        last_pointer: Option<(i32, i32)>, // screen px, to spot mouse movement
+
+        pub inventory: Inventory,
+        pub gait: Gait,
+
+        step_timer: u32,
+
+        // ─── Damage ───
+        invuln_timer: u32,          // > 0 → i-frames, Santa blinks
+        knockback: (f32, f32),      // px/frame, decays to zero
+        pending_shake: f32,         // picked up by GameState each frame
+        pub last_hit: Option<DamageSource>,
+
+        swing_timer: u32,    // > 0 while the cane is mid-swing
+        melee_cooldown: u32,
         
 
     }
@@ -59,7 +371,19 @@ pub enum WeaponMode {
         facing_x: 1.0,          // ✅ default facing right
         facing: (0.0, 1.0),     // down for melee
         is_moving: false,
-        weapon: WeaponMode::Snowball,
+        aim: (0.0, 1.0),
+        aim_mode: AimMode::Movement,
+        aim_hold: 0,
+        last_pointer: None,
+        gait: Gait::Walk,
+        step_timer: 0,
+        invuln_timer: 0,
+        knockback: (0.0, 0.0),
+        pending_shake: 0.0,
+        last_hit: None,
+        swing_timer: 0,
+        melee_cooldown: 0,
+        inventory: Inventory::new(),
 
 
     }
@@ -70,7 +394,9 @@ pub enum WeaponMode {
 
 
 
-    pub fn update(&mut self, map: &Grid) {
+    /// Moves Santa. Returns a footstep noise radius on frames where
+    /// he plants a foot.
+    pub fn update(&mut self, map: &Grid) -> Option<f32> {
 
 
         let kb = keyboard::get();
@@ -78,16 +404,25 @@ pub enum WeaponMode {
 
 
                 // ─── INPUT AS ACCELERATION ───
-        let mut ax = 0.0;
-        let mut ay = 0.0;
+        let mut ax: f32 = 0.0;
+        let mut ay: f32 = 0.0;
 
         if kb.arrow_left().pressed()  { ax -= 1.0; }
         if kb.arrow_right().pressed() { ax += 1.0; }
         if kb.arrow_up().pressed()    { ay -= 1.0; }
         if kb.arrow_down().pressed()  { ay += 1.0; }
 
+        // Sneak wins if both are held
+        self.gait = if kb.key_c().pressed() {
+            Gait::Sneak
+        } else if kb.shift_any().pressed() {
+            Gait::Sprint
+        } else {
+            Gait::Walk
+        };
+
         // Normalize diagonal acceleration
-        let len = ((ax * ax + ay * ay) as f32).sqrt();
+        let len = (ax * ax + ay * ay).sqrt();
 
         if len > 0.0 {
             ax /= len;
@@ -103,8 +438,9 @@ pub enum WeaponMode {
         }
 
         // Apply acceleration
-        self.vx += ax * self.speed;
-        self.vy += ay * self.speed;
+        let accel = self.speed * self.gait.acceleration();
+        self.vx += ax * accel;
+        self.vy += ay * accel;
 
 
         // ─── FRICTION ───
@@ -118,7 +454,7 @@ pub enum WeaponMode {
 
 
         // Clamp max speed
-        let max_speed = 2.4;
+        let max_speed = self.gait.max_speed();
         let vlen = (self.vx * self.vx + self.vy * self.vy).sqrt();
 
         if vlen > max_speed {
@@ -145,6 +481,8 @@ if vlen > 0.1 {
     self.facing = (self.vx / vlen, self.vy / vlen);
 }
 
+    self.update_aim();
+
 
 
         
@@ -192,26 +530,198 @@ if vlen > 0.1 {
 } else {
     self.vy = 0.0; // stop sliding into wall
 }
+    // ─── Knockback (same wall rules as walking) ───
+    if self.knockback.0.abs() > 0.1 || self.knockback.1.abs() > 0.1 {
+        let (kx, ky) = self.knockback;
+
+        if !Self::blocked(map, self.x + kx, self.y) {
+            self.x += kx;
+        }
+        if !Self::blocked(map, self.x, self.y + ky) {
+            self.y += ky;
+        }
+
+        self.knockback = (kx * KNOCKBACK_DECAY, ky * KNOCKBACK_DECAY);
+    }
+
+    self.invuln_timer = self.invuln_timer.saturating_sub(1);
+
     // ─── Melee timers ───
+    self.swing_timer = self.swing_timer.saturating_sub(1);
+    self.melee_cooldown = self.melee_cooldown.saturating_sub(1);
+    self.inventory.update();
 
     // ✅ UPDATE HITBOX EVERY FRAME
     self.hitbox = self.hitbox.position(self.x, self.y);
 
+    // 👣 Footsteps (louder the faster he goes)
+    let moved = (self.vx * self.vx + self.vy * self.vy).sqrt();
+    if moved <= 0.1 {
+        self.step_timer = 0;
+        return None;
+    }
+
+    self.step_timer = self.step_timer.saturating_sub(1);
+    if self.step_timer > 0 {
+        return None;
+    }
+
+    self.step_timer = FOOTSTEP_INTERVAL;
+    Some(moved * FOOTSTEP_NOISE_PER_SPEED * self.gait.noise())
+
     }
 
+        /// Would Santa's collision box overlap a wall at (x, y)?
+        fn blocked(map: &Grid, x: f32, y: f32) -> bool {
+            let left   = x + COLLISION_OFFSET;
+            let right  = x + COLLISION_OFFSET + COLLISION_SIZE - 1.0;
+            let top    = y + COLLISION_OFFSET;
+            let bottom = y + COLLISION_OFFSET + COLLISION_SIZE - 1.0;
+
+            is_wall(map, left, top)
+                || is_wall(map, right, top)
+                || is_wall(map, left, bottom)
+                || is_wall(map, right, bottom)
+        }
+
+        /// 💥 Every hit on Santa goes through here. Ignored during i-frames;
+        /// returns whether it landed. He's pushed away from `from`.
+        pub fn take_damage(&mut self, amount: i32, source: DamageSource, from: (f32, f32)) -> bool {
+            if self.invuln_timer > 0 || self.health <= 0 {
+                return false;
+            }
+
+            self.health -= amount;
+            self.invuln_timer = IFRAMES;
+            self.last_hit = Some(source);
+
+            let dx = self.x + 16.0 - from.0;
+            let dy = self.y + 16.0 - from.1;
+            let len = (dx * dx + dy * dy).sqrt();
+            if len > 0.0 {
+                self.knockback = (dx / len * HIT_KNOCKBACK, dy / len * HIT_KNOCKBACK);
+            }
+
+            self.pending_shake = self.pending_shake.max(HIT_SHAKE * amount as f32);
+            true
+        }
+
+        /// Camera shake requested by hits since the last call
+        pub fn take_shake(&mut self) -> Option<f32> {
+            if self.pending_shake <= 0.0 {
+                return None;
+            }
+
+            let amount = self.pending_shake;
+            self.pending_shake = 0.0;
+            Some(amount)
+        }
+
+        /// 🎯 WASD beats the mouse, the mouse beats walking direction.
+        /// Santa turns to face his aim so he can back away while shooting.
+        fn update_aim(&mut self) {
+            let kb = keyboard::get();
+            let mut ax: f32 = 0.0;
+            let mut ay: f32 = 0.0;
+
+            if kb.key_a().pressed() { ax -= 1.0; }
+            if kb.key_d().pressed() { ax += 1.0; }
+            if kb.key_w().pressed() { ay -= 1.0; }
+            if kb.key_s().pressed() { ay += 1.0; }
+
+            let pointer = pointer::screen().xy();
+            let pointer_moved = self.last_pointer.is_some_and(|last| last != pointer);
+            self.last_pointer = Some(pointer);
+
+            if ax != 0.0 || ay != 0.0 {
+                let len = (ax * ax + ay * ay).sqrt();
+                self.aim = (ax / len, ay / len);
+                self.aim_mode = AimMode::Keys;
+                self.aim_hold = AIM_HOLD_FRAMES;
+            } else if pointer_moved || self.aim_mode == AimMode::Mouse {
+                let (wx, wy) = pointer::world().xy();
+                let dx = wx as f32 - (self.x + 16.0);
+                let dy = wy as f32 - (self.y + 16.0);
+                let len = (dx * dx + dy * dy).sqrt();
+
+                if len > 4.0 {
+                    self.aim = (dx / len, dy / len);
+                }
+                self.aim_mode = AimMode::Mouse;
+            } else {
+                self.aim_hold = self.aim_hold.saturating_sub(1);
+                if self.aim_hold == 0 {
+                    self.aim_mode = AimMode::Movement;
+                }
+            }
+
+            if self.aim_mode == AimMode::Movement {
+                if self.is_moving {
+                    self.aim = self.facing;
+                }
+            } else if self.aim.0.abs() > 0.01 {
+                self.facing_x = self.aim.0.signum();
+            }
+        }
+
+        /// Starts a cane swing. Returns false while still on cooldown.
+        pub fn start_swing(&mut self) -> bool {
+            if self.melee_cooldown > 0 {
+                return false;
+            }
+
+            self.swing_timer = MELEE_SWING_FRAMES;
+            self.melee_cooldown = MELEE_COOLDOWN;
+            true
+        }
+
+        /// True on the one frame of a swing where the cane connects
+        pub fn swing_impact(&self) -> bool {
+            self.swing_timer == MELEE_IMPACT_FRAME
+        }
+
+        /// Whether the point (tx, ty) is inside the cane's sweep
+        pub fn in_swing_arc(&self, tx: f32, ty: f32) -> bool {
+            let dx = tx - (self.x + 16.0);
+            let dy = ty - (self.y + 16.0);
+
+            if (dx * dx + dy * dy).sqrt() > MELEE_RANGE {
+                return false;
+            }
+
+            let mut diff = dy.atan2(dx) - self.aim.1.atan2(self.aim.0);
+            while diff > std::f32::consts::PI {
+                diff -= std::f32::consts::TAU;
+            }
+            while diff < -std::f32::consts::PI {
+                diff += std::f32::consts::TAU;
+            }
+
+            diff.abs() <= MELEE_ARC.to_radians() / 2.0
+        }
+
+        /// How conspicuous Santa is right now (1.0 = normal walk)
+        pub fn visibility(&self) -> f32 {
+            self.gait.visibility()
+        }
+
         pub fn draw(&self) {
         let anim = animation::get("santa_walk_anim");
     anim.use_sprite("santa_walk");
 
     if self.is_moving {
         anim.resume();
-        anim.set_speed(1.0);
+        anim.set_speed(self.gait.anim_rate());
     } else {
         anim.pause();
         anim.restart(); // ✅ snaps to frame 0
     }
 
 
+        // 💢 Red flash on impact, then blink through the i-frames
+        let flash = self.invuln_timer > IFRAMES - HIT_FLASH_FRAMES;
+        let blink_off = self.invuln_timer > 0 && (self.invuln_timer / 4).is_multiple_of(2);
+
         // Draw animated Santa
         sprite!(
             animation_key = "santa_walk_anim",
@@ -222,10 +732,79 @@ if vlen > 0.1 {
             scale_x = 1.5,
             scale_y = 1.5,
             flip_x = self.facing_x < 0.0,
+            color = if flash { 0xff6666ff } else { 0xffffffff },
+            opacity = if blink_off && !flash { 0.3 } else { 1.0 },
             cover = true
         );
 
         // ─── Candy cane melee ───
+        if self.inventory.weapon() == WeaponMode::CandyCane {
+            self.draw_candy_cane();
+        }
+
+        self.draw_reticle();
+    }
+
+    /// Crosshair a little way out along the aim
+    fn draw_reticle(&self) {
+        let rx = (self.x + 16.0 + self.aim.0 * RETICLE_DISTANCE) as i32;
+        let ry = (self.y + 16.0 + self.aim.1 * RETICLE_DISTANCE) as i32;
+
+        circ!(
+            x = rx - 6,
+            y = ry - 6,
+            d = 12,
+            color = 0x00000000,
+            border_size = 1,
+            border_color = 0xffffffcc
+        );
+        rect!(x = rx - 1, y = ry - 1, w = 2, h = 2, color = 0xff4444ff);
+    }
+
+    /// Cane held out front, or sweeping across the arc mid-swing
+    fn draw_candy_cane(&self) {
+        let cx = self.x + 16.0;
+        let cy = self.y + 16.0;
+        let facing = self.aim.1.atan2(self.aim.0).to_degrees();
+        let half_arc = MELEE_ARC / 2.0;
+
+        let angle = if self.swing_timer > 0 {
+            let t = 1.0 - self.swing_timer as f32 / MELEE_SWING_FRAMES as f32;
+            facing - half_arc + MELEE_ARC * t
+        } else {
+            facing - half_arc
+        };
+
+        // 💨 Swoosh trail behind the cane
+        if self.swing_timer > 0 {
+            let t = 1.0 - self.swing_timer as f32 / MELEE_SWING_FRAMES as f32;
+            let alpha = ((1.0 - t) * 160.0) as u32;
+            let from = (facing - half_arc).to_radians();
+            let to = angle.to_radians();
+
+            for i in 0..6 {
+                let a0 = from + (to - from) * i as f32 / 6.0;
+                let a1 = from + (to - from) * (i + 1) as f32 / 6.0;
+                path!(
+                    start = ((cx + a0.cos() * 30.0) as i32, (cy + a0.sin() * 30.0) as i32),
+                    end = ((cx + a1.cos() * 30.0) as i32, (cy + a1.sin() * 30.0) as i32),
+                    size = 3,
+                    color = 0xffffff00 | alpha
+                );
+            }
+        }
+
+        // Sprite points up, so +90° lines it up with `angle`
+        sprite!(
+            "candy_cane",
+            x = cx as i32 - 10,
+            y = cy as i32 - 26,
+            w = 20,
+            h = 24,
+            origin = (10, 24),
+            rotation = (angle + 90.0) as i32,
+            cover = true
+        );
 
     }
 
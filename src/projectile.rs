@@ -0,0 +1,337 @@
+use turbo::*;
+use crate::{Grid, is_wall};
+use crate::noise::{GUNSHOT_NOISE, SNOWBALL_IMPACT_NOISE};
+use crate::model::BossKind;
+use crate::player::DamageSource;
+
+/// Who else hears about a snowman killed by this weapon.
+#[derive(Clone, Copy, PartialEq)]
+pub enum KillAlert {
+    Nearby,
+    Everyone,
+}
+
+#[derive(Clone, Copy)]
+pub enum Look {
+    Circle { d: u32, color: u32 },
+    Sprite { name: &'static str, size: u32 },
+    Tracer { color: u32 },
+}
+
+/// Everything that makes one weapon's shots different.
+/// A new weapon is a new constant here, not a new module.
+pub struct WeaponDef {
+    pub speed: f32,
+    pub damage: i32,
+    pub size: f32,     // square hitbox, centred on the projectile
+    pub lifetime: u32, // frames before it fizzles out
+    pub pierce: u32,   // extra targets it passes through
+    pub fire_noise: f32,
+    pub impact_noise: f32, // when it hits a wall
+    pub look: Look,
+    pub fire_sound: &'static str,
+    pub hit_sound: &'static str,
+    pub hostile: Option<DamageSource>, // fired at Santa, and by whom
+    pub kill_alert: KillAlert,
+    // Santa's side only – snowmen use their own shoot cooldown
+    pub magazine: u32,
+    pub reload_frames: u32,
+    pub fire_delay: u32, // frames between shots
+}
+
+pub const SNOWBALL: WeaponDef = WeaponDef {
+    speed: 6.0,
+    damage: 2,
+    size: 6.0,
+    lifetime: 120,
+    pierce: 0,
+    fire_noise: 0.0,
+    impact_noise: SNOWBALL_IMPACT_NOISE,
+    look: Look::Circle { d: 6, color: 0xffffffff },
+    fire_sound: "throw",
+    hit_sound: "snow_hit",
+    hostile: None,
+    kill_alert: KillAlert::Nearby,
+    magazine: 5,
+    reload_frames: 40, // packing fresh snowballs
+    fire_delay: 12,
+};
+
+pub const GUN: WeaponDef = WeaponDef {
+    speed: 6.0,
+    damage: 4,
+    size: 8.0,
+    lifetime: 120,
+    pierce: 0,
+    fire_noise: GUNSHOT_NOISE,
+    impact_noise: 0.0,
+    look: Look::Sprite { name: "gift_gun", size: 16 },
+    fire_sound: "shoot",
+    hit_sound: "hit",
+    hostile: None,
+    kill_alert: KillAlert::Everyone,
+    magazine: 6,
+    reload_frames: 75,
+    fire_delay: 10,
+};
+
+/// What snowmen shoot at Santa
+pub const SNOW_BULLET: WeaponDef = WeaponDef {
+    speed: 9.0,
+    damage: 1,
+    size: 8.0,
+    lifetime: 90,
+    pierce: 0,
+    fire_noise: 0.0,
+    impact_noise: 0.0,
+    look: Look::Tracer { color: 0xffffaa88 },
+    fire_sound: "shoot",
+    hit_sound: "bullet_snow",
+    hostile: Some(DamageSource::Snowman),
+    kill_alert: KillAlert::Nearby,
+    magazine: 0,
+    reload_frames: 0,
+    fire_delay: 0,
+};
+
+/// Snipers: one fast, hard-hitting shot down the corridor
+pub const SNIPER_SHOT: WeaponDef = WeaponDef {
+    speed: 12.0,
+    damage: 2,
+    size: 6.0,
+    lifetime: 40,
+    pierce: 0,
+    fire_noise: 0.0,
+    impact_noise: 0.0,
+    look: Look::Tracer { color: 0xddeeffff },
+    fire_sound: "shoot",
+    hit_sound: "bullet_snow",
+    hostile: Some(DamageSource::Snowman),
+    kill_alert: KillAlert::Nearby,
+    magazine: 0,
+    reload_frames: 0,
+    fire_delay: 0,
+};
+
+// ─── Boss fan attacks – slow enough to weave through ───
+pub const NIGHT_BOLT: WeaponDef = WeaponDef {
+    speed: 3.5,
+    damage: 1,
+    size: 10.0,
+    lifetime: 160,
+    pierce: 0,
+    fire_noise: 0.0,
+    impact_noise: 0.0,
+    look: Look::Circle { d: 10, color: 0x9a30ffff },
+    fire_sound: "shoot",
+    hit_sound: "hit",
+    hostile: Some(DamageSource::Boss(BossKind::NightBorne)),
+    kill_alert: KillAlert::Nearby,
+    magazine: 0,
+    reload_frames: 0,
+    fire_delay: 0,
+};
+
+pub const ICICLE: WeaponDef = WeaponDef {
+    speed: 3.0,
+    damage: 1,
+    size: 10.0,
+    lifetime: 180,
+    pierce: 0,
+    fire_noise: 0.0,
+    impact_noise: 0.0,
+    look: Look::Tracer { color: 0xbfe6ffff },
+    fire_sound: "throw",
+    hit_sound: "snow_hit",
+    hostile: Some(DamageSource::Boss(BossKind::SnowKing)),
+    kill_alert: KillAlert::Nearby,
+    magazine: 0,
+    reload_frames: 0,
+    fire_delay: 0,
+};
+
+pub const COAL: WeaponDef = WeaponDef {
+    speed: 4.5,
+    damage: 1,
+    size: 8.0,
+    lifetime: 120,
+    pierce: 0,
+    fire_noise: 0.0,
+    impact_noise: 0.0,
+    look: Look::Circle { d: 8, color: 0x2a2a2aff },
+    fire_sound: "throw",
+    hit_sound: "hit",
+    hostile: Some(DamageSource::Boss(BossKind::Krampus)),
+    kill_alert: KillAlert::Nearby,
+    magazine: 0,
+    reload_frames: 0,
+    fire_delay: 0,
+};
+
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum ProjectileKind {
+    Snowball,
+    GunBullet,
+    SnowBullet,
+    SniperShot,
+    NightBolt,
+    Icicle,
+    Coal,
+}
+
+impl ProjectileKind {
+    pub fn def(self) -> &'static WeaponDef {
+        match self {
+            ProjectileKind::Snowball => &SNOWBALL,
+            ProjectileKind::GunBullet => &GUN,
+            ProjectileKind::SnowBullet => &SNOW_BULLET,
+            ProjectileKind::SniperShot => &SNIPER_SHOT,
+            ProjectileKind::NightBolt => &NIGHT_BOLT,
+            ProjectileKind::Icicle => &ICICLE,
+            ProjectileKind::Coal => &COAL,
+        }
+    }
+}
+
+#[turbo::serialize]
+pub struct Projectile {
+    pub x: f32,
+    pub y: f32,
+    prev_x: f32,
+    prev_y: f32,
+    vx: f32,
+    vy: f32,
+    pub kind: ProjectileKind,
+    age: u32,
+    hits_left: u32,
+    pub alive: bool,
+}
+
+impl Projectile {
+    /// `dir` should be normalised; speed comes from the weapon.
+    pub fn new(kind: ProjectileKind, x: f32, y: f32, dir: (f32, f32)) -> Self {
+        let def = kind.def();
+
+        Self {
+            x,
+            y,
+            prev_x: x,
+            prev_y: y,
+            vx: dir.0 * def.speed,
+            vy: dir.1 * def.speed,
+            kind,
+            age: 0,
+            hits_left: def.pierce + 1,
+            alive: true,
+        }
+    }
+
+    pub fn def(&self) -> &'static WeaponDef {
+        self.kind.def()
+    }
+
+    /// Moves one frame. Returns true if it splatted against a wall.
+    pub fn update(&mut self, map: &Grid) -> bool {
+        if !self.alive {
+            return false;
+        }
+
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+        self.x += self.vx;
+        self.y += self.vy;
+        self.age += 1;
+
+        // ❌ Wall – stop at the last clear point so hits still sweep up to it
+        let mut clear = (self.prev_x, self.prev_y);
+        for (sx, sy) in self.sweep() {
+            if is_wall(map, sx, sy) {
+                self.x = clear.0;
+                self.y = clear.1;
+                self.alive = false;
+                return true;
+            }
+            clear = (sx, sy);
+        }
+
+        if self.age >= self.def().lifetime {
+            self.alive = false;
+        }
+
+        false
+    }
+
+    /// Did the path travelled this frame touch `target`? (no tunnelling)
+    pub fn hits(&self, target: &Bounds) -> bool {
+        let size = self.def().size;
+        let half = size / 2.0;
+
+        self.sweep().any(|(sx, sy)| {
+            Bounds::new(sx - half, sy - half, size as u32, size as u32).intersects(target)
+        })
+    }
+
+    /// Where it was last frame – the direction a hit came from
+    pub fn prev_xy(&self) -> (f32, f32) {
+        (self.prev_x, self.prev_y)
+    }
+
+    /// Counts a hit; the projectile stops once its pierce runs out.
+    pub fn register_hit(&mut self) {
+        self.hits_left = self.hits_left.saturating_sub(1);
+        if self.hits_left == 0 {
+            self.alive = false;
+        }
+    }
+
+    /// Points from last frame's position to this one, at most half a hitbox apart
+    fn sweep(&self) -> impl Iterator<Item = (f32, f32)> + use<> {
+        let (x0, y0) = (self.prev_x, self.prev_y);
+        let (dx, dy) = (self.x - x0, self.y - y0);
+        let step = (self.def().size / 2.0).max(1.0);
+        let steps = ((dx * dx + dy * dy).sqrt() / step).ceil().max(1.0) as i32;
+
+        (0..=steps).map(move |i| {
+            let t = i as f32 / steps as f32;
+            (x0 + dx * t, y0 + dy * t)
+        })
+    }
+
+    pub fn draw(&self) {
+        if !self.alive {
+            return;
+        }
+
+        match self.def().look {
+            Look::Circle { d, color } => {
+                circ!(
+                    x = self.x as i32 - d as i32 / 2,
+                    y = self.y as i32 - d as i32 / 2,
+                    d = d,
+                    color = color
+                );
+            }
+            Look::Sprite { name, size } => {
+                sprite!(
+                    name,
+                    x = self.x as i32 - size as i32 / 2,
+                    y = self.y as i32 - size as i32 / 2,
+                    w = size,
+                    h = size,
+                    cover = true
+                );
+            }
+            Look::Tracer { color } => {
+                path!(
+                    start = (self.x as i32, self.y as i32),
+                    end = (
+                        (self.x - self.vx * 2.0) as i32,
+                        (self.y - self.vy * 2.0) as i32
+                    ),
+                    color = color
+                );
+            }
+        }
+    }
+}
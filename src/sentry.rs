@@ -0,0 +1,262 @@
+use turbo::*;
+use crate::model::enemy::{cast_ray, draw_cone, wrap_angle};
+use crate::{Grid, TILE_SIZE};
+
+// ─── Security cameras ───
+const CAMERA_VISION_RADIUS: f32 = 170.0;
+const CAMERA_VISION_ANGLE: f32 = 40.0; // degrees, whole cone
+const CAMERA_SWEEP_SPEED: f32 = 0.012; // radians/frame
+const CAMERA_SWEEP_PAUSE: u32 = 40;    // frames held at each end of the arc
+const CAMERA_SPOT_FRAMES: u32 = 40;    // Santa in view this long → alarm
+const CAMERA_ALARM_FRAMES: u32 = 240;  // red and deaf after raising one
+const CAMERA_SIZE: u32 = 14;
+
+/// A camera on a floor tile. Sweeps its cone back and forth (or all the
+/// way round) and raises the alarm if Santa stays in view. One snowball
+/// knocks it out for good.
+#[turbo::serialize]
+pub struct SecurityCamera {
+    pub tile: (usize, usize),
+    pub facing: f32,
+    /// (start, arc) in radians, `None` → turns all the way round
+    sweep: Option<(f32, f32)>,
+    sweep_offset: f32, // how far into the arc it's turned
+    sweep_dir: f32,    // +1 / -1
+    sweep_pause: u32,
+    spot_timer: u32,
+    alarm_timer: u32,
+    pub disabled: bool,
+}
+
+impl SecurityCamera {
+    pub fn new(tile: (usize, usize), sweep: Option<(f32, f32)>) -> Self {
+        Self {
+            tile,
+            facing: sweep.map_or(0.0, |(start, _)| start),
+            sweep,
+            sweep_offset: 0.0,
+            sweep_dir: 1.0,
+            sweep_pause: 0,
+            spot_timer: 0,
+            alarm_timer: 0,
+            disabled: false,
+        }
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (
+            self.tile.0 as f32 * TILE_SIZE as f32 + TILE_SIZE as f32 / 2.0,
+            self.tile.1 as f32 * TILE_SIZE as f32 + TILE_SIZE as f32 / 2.0,
+        )
+    }
+
+    pub fn hitbox(&self) -> Bounds {
+        let (cx, cy) = self.center();
+        let half = CAMERA_SIZE as f32 / 2.0;
+        Bounds::new(cx - half, cy - half, CAMERA_SIZE, CAMERA_SIZE)
+    }
+
+    pub fn disable(&mut self) {
+        self.disabled = true;
+        self.spot_timer = 0;
+    }
+
+    /// Returns true the frame it raises the alarm. Cameras can't see into
+    /// hiding spots at all; `visibility` works as it does for snowmen.
+    pub fn update(&mut self, player_x: f32, player_y: f32, visibility: f32, hidden: bool, map: &Grid) -> bool {
+        if self.disabled {
+            return false;
+        }
+
+        if self.alarm_timer > 0 {
+            self.alarm_timer -= 1;
+            self.turn();
+            return false;
+        }
+
+        // Hold still and stare while Santa is in view
+        if !hidden && self.sees(player_x + 16.0, player_y + 16.0, visibility, map) {
+            self.spot_timer += 1;
+            if self.spot_timer >= CAMERA_SPOT_FRAMES {
+                self.spot_timer = 0;
+                self.alarm_timer = CAMERA_ALARM_FRAMES;
+                return true;
+            }
+            return false;
+        }
+
+        self.spot_timer = self.spot_timer.saturating_sub(2);
+        self.turn();
+        false
+    }
+
+    fn turn(&mut self) {
+        let Some((start, arc)) = self.sweep else {
+            self.facing = wrap_angle(self.facing + CAMERA_SWEEP_SPEED);
+            return;
+        };
+
+        if self.sweep_pause > 0 {
+            self.sweep_pause -= 1;
+            return;
+        }
+
+        self.sweep_offset += CAMERA_SWEEP_SPEED * self.sweep_dir;
+        if self.sweep_offset <= 0.0 || self.sweep_offset >= arc {
+            self.sweep_offset = self.sweep_offset.clamp(0.0, arc);
+            self.sweep_dir = -self.sweep_dir;
+            self.sweep_pause = CAMERA_SWEEP_PAUSE;
+        }
+
+        self.facing = wrap_angle(start + self.sweep_offset);
+    }
+
+    fn sees(&self, px: f32, py: f32, visibility: f32, map: &Grid) -> bool {
+        let (cx, cy) = self.center();
+        let dx = px - cx;
+        let dy = py - cy;
+        let dist = (dx * dx + dy * dy).sqrt();
+
+        if dist > CAMERA_VISION_RADIUS * visibility {
+            return false;
+        }
+
+        let half_cone = CAMERA_VISION_ANGLE.to_radians() / 2.0;
+        if wrap_angle(dy.atan2(dx) - self.facing).abs() > half_cone {
+            return false;
+        }
+
+        // Nothing in the way if the ray gets all the way to him
+        let (hx, hy) = cast_ray(map, cx, cy, dy.atan2(dx), dist);
+        ((hx - cx).powi(2) + (hy - cy).powi(2)).sqrt() >= dist - 4.0
+    }
+
+    pub fn draw(&self, map: &Grid, show_cone: bool, visibility: f32) {
+        let (cx, cy) = self.center();
+
+        if show_cone && !self.disabled {
+            let tint = if self.alarm_timer > 0 {
+                0xff303000
+            } else if self.spot_timer > 0 {
+                0xffb03000
+            } else {
+                0x80d0ff00
+            };
+            let half_cone = CAMERA_VISION_ANGLE.to_radians() / 2.0;
+            draw_cone(map, (cx, cy), self.facing, half_cone, CAMERA_VISION_RADIUS, visibility, tint);
+        }
+
+        // Body + lens pointing where it looks
+        let half = CAMERA_SIZE as i32 / 2;
+        let body = if self.disabled { 0x3a3a3aff } else { 0x5a6470ff };
+        rect!(
+            x = cx as i32 - half,
+            y = cy as i32 - half,
+            w = CAMERA_SIZE,
+            h = CAMERA_SIZE,
+            color = body,
+            border_radius = 3
+        );
+
+        let lx = cx + self.facing.cos() * 6.0;
+        let ly = cy + self.facing.sin() * 6.0;
+        let lens = if self.disabled {
+            0x000000ff
+        } else if self.alarm_timer > 0 && (self.alarm_timer / 8).is_multiple_of(2) {
+            0xff3b3bff
+        } else {
+            0x9ad8ffff
+        };
+        circ!(x = lx as i32 - 3, y = ly as i32 - 3, d = 6, color = lens);
+
+        // Spot meter
+        if self.spot_timer > 0 {
+            let filled = 16 * self.spot_timer / CAMERA_SPOT_FRAMES;
+            rect!(x = cx as i32 - 8, y = cy as i32 - half - 7, w = 16, h = 3, color = 0x000000aa);
+            rect!(x = cx as i32 - 8, y = cy as i32 - half - 7, w = filled, h = 3, color = 0xff9900ff);
+        }
+
+        // ⚡ Knocked out – the odd spark
+        if self.disabled && (time::tick() / 20).is_multiple_of(3) {
+            circ!(x = cx as i32 + 2, y = cy as i32 - half - 2, d = 3, color = 0xffee55ff);
+        }
+    }
+}
+
+// ─── Floor traps ───
+
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub enum TrapKind {
+    Tripwire,      // snaps once; sneaking steps over it
+    PressurePlate, // goes off every time it's stepped on
+}
+
+#[turbo::serialize]
+pub struct Trap {
+    pub tile: (usize, usize),
+    pub kind: TrapKind,
+    pressed: bool, // Santa is on it (plates) / it has snapped (wires)
+}
+
+impl Trap {
+    pub fn new(tile: (usize, usize), kind: TrapKind) -> Self {
+        Self { tile, kind, pressed: false }
+    }
+
+    /// Santa's feet at (fx, fy). Returns true the frame it goes off.
+    pub fn update(&mut self, fx: f32, fy: f32, sneaking: bool) -> bool {
+        let on_tile = fx >= 0.0
+            && fy >= 0.0
+            && (fx as i32 / TILE_SIZE) as usize == self.tile.0
+            && (fy as i32 / TILE_SIZE) as usize == self.tile.1;
+
+        match self.kind {
+            TrapKind::Tripwire => {
+                if self.pressed || !on_tile || sneaking {
+                    return false;
+                }
+                self.pressed = true;
+                true
+            }
+            TrapKind::PressurePlate => {
+                let triggered = on_tile && !self.pressed;
+                self.pressed = on_tile;
+                triggered
+            }
+        }
+    }
+
+    pub fn draw(&self) {
+        let px = self.tile.0 as i32 * TILE_SIZE;
+        let py = self.tile.1 as i32 * TILE_SIZE;
+        let mid = py + TILE_SIZE / 2;
+
+        match self.kind {
+            TrapKind::Tripwire => {
+                // Pegs either side, a thin glinting wire between
+                rect!(x = px + 2, y = mid - 3, w = 3, h = 6, color = 0x6b4a2aff);
+                rect!(x = px + TILE_SIZE - 5, y = mid - 3, w = 3, h = 6, color = 0x6b4a2aff);
+
+                if self.pressed {
+                    path!(start = (px + 4, mid), end = (px + 10, mid + 4), color = 0xc8d0d855);
+                    path!(start = (px + TILE_SIZE - 4, mid), end = (px + TILE_SIZE - 10, mid + 4), color = 0xc8d0d855);
+                } else {
+                    let glint = if (time::tick() / 30).is_multiple_of(4) { 0xffffffcc } else { 0xc8d0d877 };
+                    path!(start = (px + 4, mid), end = (px + TILE_SIZE - 4, mid), color = glint);
+                }
+            }
+            TrapKind::PressurePlate => {
+                let inset = if self.pressed { 7 } else { 5 };
+                rect!(
+                    x = px + inset,
+                    y = py + inset,
+                    w = (TILE_SIZE - inset * 2) as u32,
+                    h = (TILE_SIZE - inset * 2) as u32,
+                    color = if self.pressed { 0x5b5f66ff } else { 0x7d838cff },
+                    border_radius = 2
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use turbo::*;
+
+/// Labels in panel order – index matches `Settings::toggle`.
+const OPTIONS: [&str; 1] = ["Vision cones"];
+
+/// Player preferences. Press O in-game to open the panel
+/// (the game pauses while it's up).
+#[turbo::serialize]
+pub struct Settings {
+    pub open: bool,
+    pub show_vision_cones: bool,
+    cursor: usize,
+}
+
+impl Settings {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            show_vision_cones: true,
+            cursor: 0,
+        }
+    }
+
+    /// Handles the panel's keys. Returns true while it's open.
+    pub fn update(&mut self) -> bool {
+        let kb = keyboard::get();
+
+        if kb.key_o().just_pressed() {
+            self.open = !self.open;
+        }
+
+        if !self.open {
+            return false;
+        }
+
+        if kb.arrow_up().just_pressed() {
+            self.cursor = (self.cursor + OPTIONS.len() - 1) % OPTIONS.len();
+        }
+        if kb.arrow_down().just_pressed() {
+            self.cursor = (self.cursor + 1) % OPTIONS.len();
+        }
+        if kb.space().just_pressed() {
+            self.toggle(self.cursor);
+        }
+
+        true
+    }
+
+    fn flag_mut(&mut self, option: usize) -> &mut bool {
+        match option {
+            0 => &mut self.show_vision_cones,
+            _ => unreachable!("no setting #{option}"),
+        }
+    }
+
+    fn toggle(&mut self, option: usize) {
+        let flag = self.flag_mut(option);
+        *flag = !*flag;
+    }
+
+    fn value(&self, option: usize) -> bool {
+        match option {
+            0 => self.show_vision_cones,
+            _ => false,
+        }
+    }
+
+    pub fn draw(&self) {
+        if !self.open {
+            return;
+        }
+
+        let sw = screen().w() as i32;
+        let sh = screen().h() as i32;
+
+        let panel_w = 280;
+        let panel_h = 70 + OPTIONS.len() as i32 * 22;
+        let px = sw / 2 - panel_w / 2;
+        let py = sh / 2 - panel_h / 2;
+
+        // 🌫 Dim the game
+        rect!(x = 0, y = 0, w = sw, h = sh, fixed = true, color = 0x00000088);
+
+        // Panel
+        rect!(x = px, y = py, w = panel_w, h = panel_h, fixed = true, color = 0x101820ee);
+        rect!(x = px, y = py, w = panel_w, h = 4, fixed = true, color = 0xb11212ff);
+
+        text!("SETTINGS", x = px + 16, y = py + 14, fixed = true, scale = 2.0, color = 0xffffffff);
+
+        for (i, label) in OPTIONS.iter().enumerate() {
+            let y = py + 44 + i as i32 * 22;
+            let selected = i == self.cursor;
+            let state = if self.value(i) { "ON" } else { "OFF" };
+
+            let color = if selected { 0xffdd66ff } else { 0xccccccff };
+            let marker = if selected { ">" } else { " " };
+
+            let line = format!("{marker} {label}");
+
+            text!(&line, x = px + 16, y = y, fixed = true, scale = 1.4, color = color);
+            text!(state, x = px + panel_w - 48, y = y, fixed = true, scale = 1.4, color = color);
+        }
+
+        text!(
+            "UP/DOWN select  SPACE toggle  O close",
+            x = px + 16,
+            y = py + panel_h - 16,
+            fixed = true,
+            scale = 1.0,
+            color = 0x999999ff
+        );
+    }
+}
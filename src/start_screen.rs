@@ -121,9 +121,6 @@ impl StartScreen {
             }
 
 
-        let time = self.frame as f32;
-        let blink = 0.85 + 0.15 * (time * 0.03).sin(); // very soft pulse
-        let alpha = (blink * 255.0) as u32;
         // 🎄 Hanging Christmas lights (top edge)
         let light_h = 18;
         let mut x = 0;
@@ -319,7 +316,7 @@ let desc2 = "Take down snowmen before they see you.";
 // ---------- GAMEPLAY TUTORIAL ----------
 if !self.started {
     let tut_w = 560;
-    let tut_h = 70;
+    let tut_h = 78; // stays clear of the start prompt
     let tut_x = sw / 2 - tut_w / 2;
     let tut_y = card_y + card_h + -15;
 
@@ -351,8 +348,9 @@ if !self.started {
 );
 
 
-   let line1 = "Arrow keys = MOVE";
-let line2 = "SPACE = ATTACK / SHOOT";
+   let line1 = "Arrows = MOVE   C = SNEAK   SHIFT = RUN";
+let line2 = "SPACE = ATTACK   Q = SWITCH   R = RELOAD";
+    let line3 = "WASD/MOUSE = AIM  E = TAKEDOWN  O = SETTINGS";
 
 
     let scale = 1.8;
@@ -361,12 +359,14 @@ let line2 = "SPACE = ATTACK / SHOOT";
         (sw as f32 / 2.0 - line1.len() as f32 * 7.0 * scale / 2.0) as i32;
     let l2_x =
         (sw as f32 / 2.0 - line2.len() as f32 * 7.0 * scale / 2.0) as i32;
+    let l3_x =
+        (sw as f32 / 2.0 - line3.len() as f32 * 7.0 * scale / 2.0) as i32;
 
     // Text shadow
     text!(
         line1,
         x = l1_x + 2,
-        y = tut_y  + 28 + 2,
+        y = tut_y  + 20 + 2,
         fixed = true,
         scale = scale,
         color = 0x000000ff
@@ -374,7 +374,15 @@ let line2 = "SPACE = ATTACK / SHOOT";
     text!(
         line2,
         x = l2_x + 2,
-        y = tut_y   + 56 + 2,
+        y = tut_y   + 42 + 2,
+        fixed = true,
+        scale = scale,
+        color = 0x000000ff
+    );
+    text!(
+        line3,
+        x = l3_x + 2,
+        y = tut_y   + 64 + 2,
         fixed = true,
         scale = scale,
         color = 0x000000ff
@@ -386,7 +394,7 @@ let line2 = "SPACE = ATTACK / SHOOT";
     text!(
         line1,
         x = l1_x,
-        y = tut_y  + 28,
+        y = tut_y  + 20,
         fixed = true,
         scale = scale,
         color = 0xffffffff
@@ -394,7 +402,15 @@ let line2 = "SPACE = ATTACK / SHOOT";
     text!(
         line2,
         x = l2_x,
-        y = tut_y  + 56,
+        y = tut_y  + 42,
+        fixed = true,
+        scale = scale,
+        color = 0xffffffff
+    );
+    text!(
+        line3,
+        x = l3_x,
+        y = tut_y  + 64,
         fixed = true,
         scale = scale,
         color = 0xffffffff